@@ -0,0 +1,254 @@
+#![expect(unsafe_code, reason = "wrap an owning raw pointer with weaker aliasing requirements")]
+
+use alloc::boxed::Box;
+use core::{cmp::Ordering, marker::PhantomData, mem::ManuallyDrop, ptr::NonNull};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+};
+
+use crate::deref_view::{DerefView, DerefViewMut};
+use crate::traits::{AliasableView, AliasableViewMut, View, ViewMut};
+
+
+/// A non-unique version of `Box<T>` which can be freely moved without invalidating pointers or
+/// references derived from it.
+///
+/// In current aliasing models, moving a `Box<T>` introduces an exclusive retag (much like moving a
+/// `&mut T`), which invalidates pointers or references derived from the moved-from value of the
+/// box. An `AliasableBox<T>` owns the same heap allocation as a `Box<T>` would, but stores it
+/// behind a raw pointer, whose moves do not retag.
+///
+/// Converting an `AliasableBox<T>` back into a `Box<T>` restores the normal aliasing requirements
+/// of `Box<T>`; any pointers or references previously derived from the `AliasableBox<T>` should
+/// be considered invalidated by the conversion.
+///
+/// # Layout
+/// This type is a transparent wrapper around a `NonNull<T>`, which points to an allocation that
+/// is owned by the `AliasableBox<T>` and was allocated as a `Box<T>`. Of course, many invariants
+/// are required of that `NonNull<T>` beyond simply being non-null; do not recklessly transmute
+/// this type and write to its pointer.
+#[repr(transparent)]
+pub struct AliasableBox<T: ?Sized> {
+    /// # Safety invariant
+    /// This pointer was obtained from `Box::into_raw` in [`Self::from_box`], and the allocation
+    /// it points to is owned by `self`. It can always be converted back into a `Box<T>` (at the
+    /// expense of invalidating pointers and references previously derived from `self.ptr`), and
+    /// doing so is the responsibility of either [`Self::into_box`] or the destructor of `Self`.
+    ///
+    /// Methods of this type converting `self.ptr` into a (possibly mutable) reference must
+    /// follow the same rules as [`AliasableRefMut`]: methods taking `&Self` are only permitted to
+    /// convert `self.ptr` to a `&T`, while methods taking `&mut Self` or `Self` may convert it to
+    /// a `&T` or `&mut T`. No method may directly read or write through `self.ptr`.
+    ///
+    /// Only [`Self::from_box`] is permitted to directly construct `Self`.
+    ///
+    /// [`AliasableRefMut`]: crate::AliasableRefMut
+    ptr:    NonNull<T>,
+    _owned: PhantomData<Box<T>>,
+}
+
+impl<T> AliasableBox<T> {
+    /// Allocate `value` on the heap in an `AliasableBox<T>`.
+    #[inline]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self::from_box(Box::new(value))
+    }
+}
+
+impl<T: ?Sized> AliasableBox<T> {
+    /// Take ownership of the allocation of a `Box<T>`.
+    #[inline]
+    #[must_use]
+    pub fn from_box(boxed: Box<T>) -> Self {
+        // SAFETY: `Box::into_raw` returns a non-null pointer.
+        let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(boxed)) };
+        // SAFETY INVARIANT: `ptr` was just obtained from `Box::into_raw`, and we take ownership
+        // of its allocation. Additionally, using the explicit constructor is only permitted in
+        // `AliasableBox::from_box`, which is this function.
+        Self {
+            ptr,
+            _owned: PhantomData,
+        }
+    }
+
+    /// Convert this `AliasableBox<T>` back into a `Box<T>`, with the normal aliasing requirements
+    /// of `Box<T>`.
+    ///
+    /// Any pointers or references previously derived from this `AliasableBox<T>` should be
+    /// considered invalidated.
+    ///
+    /// (`From<AliasableBox<T>> for Box<T>` cannot be implemented, as `Box` is a fundamental type
+    /// and the orphan rules forbid it; this method is provided instead.)
+    #[inline]
+    #[must_use]
+    pub fn into_box(self) -> Box<T> {
+        // The allocation is now owned by the returned box, so our destructor must not run.
+        let this = ManuallyDrop::new(self);
+        // SAFETY: by the safety invariant, `this.ptr` was obtained from `Box::into_raw`, and the
+        // allocation is owned by `this`. Since `this` is never dropped, ownership of the
+        // allocation is transferred to the returned box.
+        unsafe { Box::from_raw(this.ptr.as_ptr()) }
+    }
+}
+
+impl<T: ?Sized> Drop for AliasableBox<T> {
+    fn drop(&mut self) {
+        // SAFETY: by the safety invariant, `self.ptr` was obtained from `Box::into_raw`, and the
+        // allocation is owned by `self`. Since `self` is being dropped, nothing else will use
+        // `self.ptr` to access the allocation after this point. Safe code cannot hold any
+        // reference obtained from `self` across this call, and dropping `Self` is not among the
+        // operations which `unsafe` code is permitted to perform while continuing to use
+        // lifetime-extended references or views of `self`; therefore, no such reference or view
+        // is used again once the allocation is freed.
+        drop(unsafe { Box::from_raw(self.ptr.as_ptr()) });
+    }
+}
+
+impl<T: ?Sized> From<Box<T>> for AliasableBox<T> {
+    #[inline]
+    fn from(boxed: Box<T>) -> Self {
+        Self::from_box(boxed)
+    }
+}
+
+
+impl<T: ?Sized> Deref for AliasableBox<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: this is a method of `AliasableBox` with a `&Self` argument, so as per the
+        // safety invariant of `self.ptr`, creating a `&T` from `self.ptr` with the lifetime of
+        // `self` is sound. The pointee is a valid `T` in an allocation owned by `self`.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for AliasableBox<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: this is a method of `AliasableBox` with a `&mut Self` argument, so as per the
+        // safety invariant of `self.ptr`, creating a `&mut T` from `self.ptr` with the lifetime
+        // of `self` is sound. The pointee is a valid `T` in an allocation owned by `self`.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for AliasableBox<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> AsMut<T> for AliasableBox<T> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for AliasableBox<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for AliasableBox<T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+/// Unlike a `Box<T>`, an `AliasableBox<T>` can be moved without invalidating its views.
+///
+/// # Example
+/// ```
+/// use aliasable_view::{AliasableBox, AliasableView};
+///
+/// let boxed = AliasableBox::new(String::from("hello"));
+/// let view: *const String = boxed.view();
+///
+/// let moved = boxed;
+/// // SAFETY: the `AliasableBox` has only been moved since `view` was obtained.
+/// let view = unsafe { &*view };
+/// assert_eq!(view, "hello");
+/// assert_eq!(*moved, "hello");
+/// ```
+// SAFETY: Moving or coercing an `AliasableBox<T>` only moves its `NonNull<T>`, which does not
+// retag or otherwise invalidate pointers to the heap allocation, and the allocation is only
+// freed by the destructor or by `into_box`, neither of which is a permitted operation. Methods
+// taking `&Self` only ever convert `self.ptr` to a `&T`, as per the safety invariant of
+// `self.ptr`, so they cannot invalidate the returned `&T` views.
+unsafe impl<T: ?Sized> AliasableView for AliasableBox<T> {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+// SAFETY: Moving or coercing an `AliasableBox<T>` only moves its `NonNull<T>`, which does not
+// retag or otherwise invalidate pointers to the heap allocation, and the allocation is only
+// freed by the destructor or by `into_box`, neither of which is a permitted operation.
+unsafe impl<T: ?Sized> AliasableViewMut for AliasableBox<T> {
+    type ViewMut = DerefViewMut;
+
+    #[inline]
+    fn view_mut(&mut self) -> ViewMut<'_, Self> {
+        self
+    }
+}
+
+// SAFETY: Since `AliasableBox<T>` acts like `Box<T>`, it can be `Send` if `Box<T>` is `Send`.
+// We know that `Box<T>` is `Send` iff `T` is `Send`.
+unsafe impl<T: ?Sized + Send> Send for AliasableBox<T> {}
+
+// SAFETY: Since `AliasableBox<T>` acts like `Box<T>`, it can be `Sync` if `Box<T>` is `Sync`.
+// We know that `Box<T>` is `Sync` iff `T` is `Sync`.
+unsafe impl<T: ?Sized + Sync> Sync for AliasableBox<T> {}
+
+impl<T: ?Sized + Debug> Debug for AliasableBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + Display> Display for AliasableBox<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + PartialEq> PartialEq for AliasableBox<T> {
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(&**self, &**other)
+    }
+}
+
+impl<T: ?Sized + Eq> Eq for AliasableBox<T> {}
+
+impl<T: ?Sized + PartialOrd> PartialOrd for AliasableBox<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T: ?Sized + Ord> Ord for AliasableBox<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for AliasableBox<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state);
+    }
+}
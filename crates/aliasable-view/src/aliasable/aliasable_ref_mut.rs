@@ -1,14 +1,20 @@
-use core::{cmp::Ordering, marker::PhantomData, pin::Pin, ptr::NonNull};
+#![expect(unsafe_code, reason = "wrap a raw pointer with weaker aliasing requirements")]
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{cmp::Ordering, marker::PhantomData, pin::Pin, ptr::NonNull, slice::SliceIndex};
 use core::{
-    fmt::{Debug, Formatter, Result as FmtResult},
+    borrow::{Borrow, BorrowMut},
+    fmt::{Arguments, Debug, Display, Formatter, Result as FmtResult, Write},
     hash::{Hash, Hasher},
     ops::{Deref, DerefMut},
 };
 
-use variance_family::VaryingRef;
-
+use crate::deref_view::{DerefView, DerefViewMut};
 use crate::traits::{AliasableView, AliasableViewMut, View, ViewMut};
 
+use super::aliasable_slice_iter_mut::AliasableSliceIterMut;
+
 
 /// A non-unique version of `&'a mut T` which can be freely moved without invalidating pointers
 /// or references derived from it.
@@ -73,6 +79,66 @@ use crate::traits::{AliasableView, AliasableViewMut, View, ViewMut};
 /// If a more suitable type ever becomes available (such as a pointer type with alignment, non-null,
 /// dereferenceability, and pointee validity requirements but the weak aliasing requirements of
 /// raw pointers), a breaking change might be made to change the layout.
+///
+/// # Examples
+/// Both aliasing guarantees can be exercised by deriving a raw pointer from the reference returned
+/// by [`Deref`] or [`DerefMut`] and continuing to use it after the `AliasableRefMut` is moved.
+///
+/// A `&T` survives a move and a drop:
+/// ```
+/// use aliasable_view::AliasableRefMut;
+///
+/// let mut value = 5_u32;
+/// let aliasable = AliasableRefMut::from_mut(&mut value);
+/// let shared: *const u32 = &raw const *aliasable;
+///
+/// let moved = aliasable;
+/// // SAFETY: `shared` was derived from a `&u32` obtained via `Deref`, and the `AliasableRefMut`
+/// // has only been moved since then.
+/// assert_eq!(unsafe { *shared }, 5);
+///
+/// drop(moved);
+/// // SAFETY: dropping the `AliasableRefMut` does not invalidate `shared`, either.
+/// assert_eq!(unsafe { *shared }, 5);
+/// ```
+///
+/// A `&mut T` survives a move:
+/// ```
+/// use aliasable_view::AliasableRefMut;
+///
+/// let mut value = 5_u32;
+/// let mut aliasable = AliasableRefMut::from_mut(&mut value);
+/// let exclusive: *mut u32 = &raw mut *aliasable;
+///
+/// let moved = aliasable;
+/// // SAFETY: `exclusive` was derived from a `&mut u32` obtained via `DerefMut`, and the
+/// // `AliasableRefMut` has only been moved since then.
+/// unsafe { *exclusive += 1 };
+///
+/// drop(moved);
+/// assert_eq!(value, 6);
+/// ```
+///
+/// Moves performed by a reallocating `Vec` are no different:
+/// ```
+/// use aliasable_view::AliasableRefMut;
+///
+/// let mut first = 1_u32;
+/// let mut second = 2_u32;
+///
+/// let mut refs = Vec::with_capacity(1);
+/// refs.push(AliasableRefMut::from_mut(&mut first));
+/// let exclusive: *mut u32 = &raw mut *refs[0];
+///
+/// // Exceeding the capacity moves the existing element to a new allocation.
+/// refs.push(AliasableRefMut::from_mut(&mut second));
+/// // SAFETY: `exclusive` was derived from a `&mut u32` obtained via `DerefMut`, and the
+/// // `AliasableRefMut` it came from has only been moved since then.
+/// unsafe { *exclusive += 10 };
+///
+/// drop(refs);
+/// assert_eq!(first, 11);
+/// ```
 #[repr(transparent)]
 pub struct AliasableRefMut<'a, T: ?Sized> {
     /// # Safety invariant
@@ -255,6 +321,35 @@ impl<'a, T: ?Sized> AliasableRefMut<'a, T> {
         unsafe { self.ptr.as_mut() }
     }
 
+    /// Project this `AliasableRefMut` to some part of its pointee, such as a field.
+    ///
+    /// This is equivalent to `AliasableRefMut::from_mut(f(self.into_mut()))`.
+    ///
+    /// # Example
+    /// ```
+    /// use aliasable_view::AliasableRefMut;
+    ///
+    /// struct Pair {
+    ///     first:  u32,
+    ///     second: u32,
+    /// }
+    ///
+    /// let mut pair = Pair { first: 1, second: 2 };
+    /// let mut second = AliasableRefMut::from_mut(&mut pair).map(|pair| &mut pair.second);
+    /// *second += 10;
+    ///
+    /// assert_eq!(pair.first, 1);
+    /// assert_eq!(pair.second, 12);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn map<U: ?Sized, F>(self, f: F) -> AliasableRefMut<'a, U>
+    where
+        F: FnOnce(&'a mut T) -> &'a mut U,
+    {
+        AliasableRefMut::from_mut(f(self.into_mut()))
+    }
+
     #[inline]
     #[must_use]
     pub const fn from_pin_mut(ptr: Pin<&'a mut T>) -> Pin<Self> {
@@ -300,6 +395,109 @@ impl<'a, T: ?Sized> AliasableRefMut<'a, T> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> AliasableRefMut<'_, T> {
+    /// Recover the `Box<T>` whose allocation this `AliasableRefMut` refers to, such as one
+    /// obtained from [`Box::leak`].
+    ///
+    /// This is equivalent to `Box::from_raw(self.into_mut())`. There is intentionally no
+    /// corresponding `from_box` constructor; heap-owned data which should be aliasable is better
+    /// stored in an [`AliasableBox<T>`], which frees its allocation on drop and offers the same
+    /// aliasing guarantees without any `unsafe`.
+    ///
+    /// # Safety
+    /// The pointee of `self` must be the entire contents of an allocation which was leaked from a
+    /// `Box<T>` (for instance, via [`Box::leak`] or [`Box::into_raw`]), and `self` must have been
+    /// constructed from a reference derived from that leaked allocation. Ownership of the
+    /// allocation is transferred to the returned box, so no other pointer or reference to the
+    /// pointee may be used afterwards (in particular, the allocation must not be freed twice).
+    ///
+    /// # Example
+    /// ```
+    /// use aliasable_view::AliasableRefMut;
+    ///
+    /// let leaked: &mut u32 = Box::leak(Box::new(5));
+    /// let mut aliasable = AliasableRefMut::from_mut(leaked);
+    /// *aliasable += 1;
+    ///
+    /// // SAFETY: `aliasable` refers to the whole allocation leaked from the box above, and
+    /// // nothing else accesses that allocation.
+    /// let boxed = unsafe { aliasable.into_box() };
+    /// assert_eq!(*boxed, 6);
+    /// ```
+    ///
+    /// [`AliasableBox<T>`]: crate::AliasableBox
+    #[inline]
+    #[must_use]
+    pub unsafe fn into_box(self) -> Box<T> {
+        // SAFETY: the caller asserts that `self.into_mut()` points to the entire allocation of a
+        // leaked `Box<T>`, with provenance derived from that leaked allocation, and that
+        // ownership of the allocation may be transferred to the returned box.
+        unsafe { Box::from_raw(self.into_mut()) }
+    }
+}
+
+impl<'a, T, const N: usize> AliasableRefMut<'a, [T; N]> {
+    /// Coerce this array reference into a slice reference.
+    ///
+    /// This is equivalent to `AliasableRefMut::from_mut(self.into_mut() as &mut [T])`.
+    ///
+    /// # Example
+    /// ```
+    /// use aliasable_view::AliasableRefMut;
+    ///
+    /// let mut buffer = [0_u8; 4];
+    /// let mut slice = AliasableRefMut::from_mut(&mut buffer).into_slice();
+    /// slice[1..].fill(7);
+    ///
+    /// assert_eq!(slice.len(), 4);
+    /// assert_eq!(buffer, [0, 7, 7, 7]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_slice(self) -> AliasableRefMut<'a, [T]> {
+        let array: &'a mut [T; N] = self.into_mut();
+        AliasableRefMut::from_mut(array)
+    }
+}
+
+impl<T> AliasableRefMut<'_, [T]> {
+    /// Returns a reference to an element or subslice, or `None` if the index is out of bounds.
+    ///
+    /// See [`slice::get`]. The aliasing guarantees of `AliasableRefMut` for `&T` references
+    /// apply to the returned reference.
+    #[inline]
+    #[must_use]
+    pub fn get<I: SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+        (**self).get(index)
+    }
+
+    /// Returns a reference to an element or subslice, without doing bounds checking.
+    ///
+    /// See [`slice::get_unchecked`]. The aliasing guarantees of `AliasableRefMut` for `&T`
+    /// references apply to the returned reference.
+    ///
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is undefined behavior, even if the resulting
+    /// reference is not used.
+    #[inline]
+    #[must_use]
+    pub unsafe fn get_unchecked<I: SliceIndex<[T]>>(&self, index: I) -> &I::Output {
+        // SAFETY: the caller asserts that `index` is in bounds.
+        unsafe { (**self).get_unchecked(index) }
+    }
+
+    /// Returns an iterator which yields an `AliasableRefMut` to each element of the slice.
+    ///
+    /// The elements do not overlap, so the yielded `AliasableRefMut` values can be used
+    /// simultaneously. Each yielded value provides the aliasing guarantees of `AliasableRefMut`
+    /// for its own element.
+    #[inline]
+    pub fn iter_aliasable_mut(&mut self) -> AliasableSliceIterMut<'_, T> {
+        AliasableSliceIterMut::new(self)
+    }
+}
+
 impl<T: ?Sized> Deref for AliasableRefMut<'_, T> {
     type Target = T;
 
@@ -338,6 +536,22 @@ impl<T: ?Sized> AsMut<T> for AliasableRefMut<'_, T> {
     }
 }
 
+impl<T: ?Sized> Borrow<T> for AliasableRefMut<'_, T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        // Note that the aliasing guarantees of `AliasableRefMut` apply to the returned reference.
+        self
+    }
+}
+
+impl<T: ?Sized> BorrowMut<T> for AliasableRefMut<'_, T> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut T {
+        // Note that the aliasing guarantees of `AliasableRefMut` apply to the returned reference.
+        self
+    }
+}
+
 impl<'a, T: ?Sized> From<&'a mut T> for AliasableRefMut<'a, T> {
     #[inline]
     fn from(ptr: &'a mut T) -> Self {
@@ -345,33 +559,33 @@ impl<'a, T: ?Sized> From<&'a mut T> for AliasableRefMut<'a, T> {
     }
 }
 
-// // SAFETY: By the aliasing guarantee of `AliasableRefMut` for `&T` references obtained from
-// // `Deref::deref` (among other methods), moving values of `Self`, coercing them, or performing
-// // operations on `&Self` will not invalidate the returned `&T` views. (In fact, `AliasableRefMut`
-// // guarantees that dropping it will not invalidate views, either, which is stronger than the
-// // requirement imposed by `AliasableView`.)
-// unsafe impl<T: ?Sized> AliasableView for AliasableRefMut<'_, T> {
-//     type View = /* &'varying T */ ();
-
-//     #[inline]
-//     fn view(&self) -> View<'_, Self> {
-//         self
-//     }
-// }
-
-// // SAFETY: By the aliasing guarantee of `AliasableRefMut` for `&mut T` references obtained from
-// // `DerefMut::deref_mut` (among other methods), moving or coercing values of `Self` will not
-// // invalidate the returned `&mut T` views. (In fact, `AliasableRefMut` guarantees that dropping it
-// // will not invalidate views, either, which is stronger than the requirement imposed by
-// // `AliasableViewMut`.)
-// unsafe impl<T: ?Sized> AliasableViewMut for AliasableRefMut<'_, T> {
-//     type ViewMut = /* &'varying mut T */ ();
-
-//     #[inline]
-//     fn view_mut(&mut self) -> ViewMut<'_, Self> {
-//         self
-//     }
-// }
+// SAFETY: By the aliasing guarantee of `AliasableRefMut` for `&T` references obtained from
+// `Deref::deref` (among other methods), moving values of `Self`, coercing them, or performing
+// operations on `&Self` will not invalidate the returned `&T` views. (In fact, `AliasableRefMut`
+// guarantees that dropping it will not invalidate views, either, which is stronger than the
+// requirement imposed by `AliasableView`.)
+unsafe impl<T: ?Sized> AliasableView for AliasableRefMut<'_, T> {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+// SAFETY: By the aliasing guarantee of `AliasableRefMut` for `&mut T` references obtained from
+// `DerefMut::deref_mut` (among other methods), moving or coercing values of `Self` will not
+// invalidate the returned `&mut T` views. (In fact, `AliasableRefMut` guarantees that dropping it
+// will not invalidate views, either, which is stronger than the requirement imposed by
+// `AliasableViewMut`.)
+unsafe impl<T: ?Sized> AliasableViewMut for AliasableRefMut<'_, T> {
+    type ViewMut = DerefViewMut;
+
+    #[inline]
+    fn view_mut(&mut self) -> ViewMut<'_, Self> {
+        self
+    }
+}
 
 // SAFETY: Since `AliasableRefMut<'_, T>` acts like `&mut T`,
 // it can be `Send` if `&mut T` is `Send`. We know that `&mut T` is `Send` iff `T` is `Send`.
@@ -387,6 +601,29 @@ impl<T: ?Sized + Debug> Debug for AliasableRefMut<'_, T> {
     }
 }
 
+impl<T: ?Sized + Display> Display for AliasableRefMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + Write> Write for AliasableRefMut<'_, T> {
+    #[inline]
+    fn write_str(&mut self, string: &str) -> FmtResult {
+        (**self).write_str(string)
+    }
+
+    #[inline]
+    fn write_char(&mut self, character: char) -> FmtResult {
+        (**self).write_char(character)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments<'_>) -> FmtResult {
+        (**self).write_fmt(args)
+    }
+}
+
 impl<A: ?Sized + PartialEq<B>, B: ?Sized> PartialEq<AliasableRefMut<'_, B>> for AliasableRefMut<'_, A> {
     fn eq(&self, other: &AliasableRefMut<'_, B>) -> bool {
         PartialEq::eq(&**self, &**other)
@@ -0,0 +1,75 @@
+use core::iter::FusedIterator;
+use core::slice::IterMut;
+
+use super::aliasable_ref_mut::AliasableRefMut;
+
+
+/// An iterator over the elements of a slice, yielding an [`AliasableRefMut`] to each element.
+///
+/// This struct is created by [`AliasableRefMut::iter_aliasable_mut`]. The elements do not overlap,
+/// so the yielded values can be used simultaneously, and moving the iterator (or any yielded
+/// value) does not invalidate the other yielded values.
+///
+/// # Example
+/// ```
+/// use aliasable_view::AliasableRefMut;
+///
+/// let mut data = [1, 2, 3];
+/// let mut slice = AliasableRefMut::from_mut(&mut data[..]);
+///
+/// let mut iter = slice.iter_aliasable_mut();
+/// let mut first = iter.next().unwrap();
+/// let moved_iter = iter;
+/// let mut rest: Vec<_> = moved_iter.rev().collect();
+///
+/// *first += 10;
+/// *rest[0] += 20;
+/// *rest[1] += 30;
+/// drop((first, rest));
+///
+/// assert_eq!(*slice, [11, 32, 23]);
+/// ```
+#[derive(Debug)]
+pub struct AliasableSliceIterMut<'a, T> {
+    inner: IterMut<'a, T>,
+}
+
+impl<'a, T> AliasableSliceIterMut<'a, T> {
+    #[inline]
+    #[must_use]
+    pub(super) fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            inner: slice.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T> Iterator for AliasableSliceIterMut<'a, T> {
+    type Item = AliasableRefMut<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(AliasableRefMut::from_mut)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for AliasableSliceIterMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(AliasableRefMut::from_mut)
+    }
+}
+
+impl<T> ExactSizeIterator for AliasableSliceIterMut<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> FusedIterator for AliasableSliceIterMut<'_, T> {}
@@ -2,6 +2,8 @@
 // for 4-5 years and has substantial UB. No clue why the changes on its repo haven't been pushed.
 
 mod aliasable_ref_mut;
+mod aliasable_slice_iter_mut;
+#[cfg(feature = "alloc")]
 mod aliasable_box;
 
 // Currently, `Vec` and friends are already aliasable. If that ever changes for whatever reason,
@@ -9,3 +11,6 @@ mod aliasable_box;
 // and make `AliasableVec`, `AliasableString`, `AliasableCowSlice`, etc.
 
 pub use self::aliasable_ref_mut::AliasableRefMut;
+pub use self::aliasable_slice_iter_mut::AliasableSliceIterMut;
+#[cfg(feature = "alloc")]
+pub use self::aliasable_box::AliasableBox;
@@ -0,0 +1,73 @@
+use alloc::sync::Arc;
+
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableClone, AliasableView, View};
+
+
+/// Unsized pointees, including trait objects, are supported; their views are ordinary `&T` fat
+/// references, so pointer metadata such as a length or vtable is preserved. In particular,
+/// `Arc<str>` and `Arc<[u8]>` are supported as shared, immutable buffers.
+///
+/// # Examples
+/// ```
+/// use std::{any::Any, sync::Arc};
+/// use aliasable_view::AliasableView;
+///
+/// let text: Arc<str> = Arc::from("hello");
+/// let bytes: Arc<[u8]> = Arc::from(&b"world"[..]);
+/// assert_eq!(text.view(), "hello");
+/// assert_eq!(bytes.view(), b"world");
+///
+/// let erased: Arc<dyn Any + Send + Sync> = Arc::new(String::from("hello"));
+/// let view: &(dyn Any + Send + Sync) = erased.view();
+/// assert_eq!(view.downcast_ref::<String>().map(String::as_str), Some("hello"));
+/// ```
+// SAFETY: An `Arc<T>` points to a heap allocation which is not moved, accessed, or deallocated
+// when the `Arc<T>` is moved or coerced, so the `&T` views remain valid. Operations on `&Arc<T>`
+// (including `Arc::clone`) only access the reference counts of the allocation, and never mutate
+// the `T` value (except via `T`'s own `&T` methods, which are already permitted to be called
+// while `&T` references exist). Lastly, `Arc<T>` does nothing silly to detect moves.
+unsafe impl<T: ?Sized> AliasableView for Arc<T> {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+
+/// A view of an `Arc<T>` remains valid for as long as any clone of that `Arc<T>` is alive, provided
+/// that none of the clones are mutated or unwrapped (as with `Arc::get_mut`, `Arc::make_mut`, or
+/// `Arc::try_unwrap`). This allows the data of a self-referential struct to be shared with other
+/// owners.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use aliasable_view::AliasableView;
+///
+/// let (clone, view) = {
+///     let original: Arc<str> = Arc::from("hello");
+///     let view: *const str = original.view();
+///     (Arc::clone(&original), view)
+///     // `original` is dropped here.
+/// };
+///
+/// // SAFETY: `clone` is a sibling clone of `original` which has not been dropped, so as per
+/// // `AliasableClone`, the view of `original` is still valid.
+/// let view = unsafe { &*view };
+/// assert_eq!(view, "hello");
+/// assert_eq!(Arc::strong_count(&clone), 1);
+/// ```
+// SAFETY: Sibling clones of an `Arc<T>` share the same heap allocation, which is only
+// deallocated (and the `T` value only dropped) once every strong reference has been dropped.
+// `AliasableClone` only requires the views to remain valid while every sibling clone is only
+// moved, coerced, accessed through a `&Arc<T>`, or dropped while another sibling remains; in
+// particular, no sibling is passed to `Arc::get_mut`, `Arc::make_mut`, `Arc::try_unwrap`, or
+// `Arc::into_inner` in the meantime, so each sibling keeps its strong reference to the shared
+// allocation until it is dropped, and nothing obtains a `&mut T` or moves the `T` value. None of
+// the operations on `&Arc<T>` (such as `Arc::clone` or `Arc::downgrade`) mutate the `T` value.
+// Therefore, dropping an `Arc<T>` while a sibling clone has not been dropped merely decrements
+// the strong count, and does not access the `T` value or invalidate the `&T` views.
+unsafe impl<T: ?Sized> AliasableClone for Arc<T> {}
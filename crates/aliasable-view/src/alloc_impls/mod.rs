@@ -1,3 +1,5 @@
+#![expect(unsafe_code, reason = "implement unsafe marker traits")]
+
 mod box_impl;
 mod vec;
 mod string;
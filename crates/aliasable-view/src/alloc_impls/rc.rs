@@ -0,0 +1,56 @@
+use alloc::rc::Rc;
+
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableClone, AliasableView, View};
+
+
+// SAFETY: An `Rc<T>` points to a heap allocation which is not moved, accessed, or deallocated
+// when the `Rc<T>` is moved or coerced, so the `&T` views remain valid. Operations on `&Rc<T>`
+// (including `Rc::clone`) only access the reference counts of the allocation, and never mutate
+// the `T` value (except via `T`'s own `&T` methods, which are already permitted to be called
+// while `&T` references exist). Lastly, `Rc<T>` does nothing silly to detect moves.
+unsafe impl<T: ?Sized> AliasableView for Rc<T> {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+
+/// A view of an `Rc<T>` remains valid for as long as any clone of that `Rc<T>` is alive, whether
+/// the clone was made with `Rc::clone` or `Rc::clone_from`, provided that none of the clones are
+/// mutated or unwrapped (as with `Rc::get_mut`, `Rc::make_mut`, or `Rc::try_unwrap`).
+///
+/// # Example
+/// ```
+/// use std::rc::Rc;
+/// use aliasable_view::AliasableView;
+///
+/// let mut clone: Rc<str> = Rc::from("unrelated");
+/// let view = {
+///     let original: Rc<str> = Rc::from("hello");
+///     let view: *const str = original.view();
+///     clone.clone_from(&original);
+///     view
+///     // `original` is dropped here.
+/// };
+///
+/// // SAFETY: `clone` is a sibling clone of `original` which has not been dropped, so as per
+/// // `AliasableClone`, the view of `original` is still valid.
+/// let view = unsafe { &*view };
+/// assert_eq!(view, "hello");
+/// assert_eq!(Rc::strong_count(&clone), 1);
+/// ```
+// SAFETY: Sibling clones of an `Rc<T>` share the same heap allocation, which is only
+// deallocated (and the `T` value only dropped) once every strong reference has been dropped.
+// `AliasableClone` only requires the views to remain valid while every sibling clone is only
+// moved, coerced, accessed through a `&Rc<T>`, or dropped while another sibling remains; in
+// particular, no sibling is passed to `Rc::get_mut`, `Rc::make_mut`, `Rc::try_unwrap`, or
+// `Rc::into_inner` in the meantime, so each sibling keeps its strong reference to the shared
+// allocation until it is dropped, and nothing obtains a `&mut T` or moves the `T` value. None of
+// the operations on `&Rc<T>` (such as `Rc::clone` or `Rc::downgrade`) mutate the `T` value.
+// Therefore, dropping an `Rc<T>` while a sibling clone has not been dropped merely decrements
+// the strong count, and does not access the `T` value or invalidate the `&T` views.
+unsafe impl<T: ?Sized> AliasableClone for Rc<T> {}
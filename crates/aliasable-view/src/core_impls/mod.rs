@@ -1,3 +1,5 @@
+#![expect(unsafe_code, reason = "implement unsafe marker traits")]
+
 mod ref_impl;
 mod ref_mut_impl;
 
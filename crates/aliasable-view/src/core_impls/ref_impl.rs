@@ -0,0 +1,59 @@
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableClone, AliasableView, View};
+
+
+/// A shared reference is a zero-cost source of views, with no allocation or reference counting;
+/// for instance, `&'static str` and `&'static [u8]` literals can be used as the data of a
+/// self-referential struct.
+///
+/// # Example
+/// ```
+/// use aliasable_view::AliasableView;
+///
+/// const PATTERN: &str = "GET /index.html";
+///
+/// let source: &'static str = PATTERN;
+/// let view: &str = source.view();
+/// assert_eq!(view.split(' ').nth(1), Some("/index.html"));
+///
+/// let bytes: &'static [u8] = b"\x7fELF";
+/// assert_eq!(bytes.view(), b"\x7fELF");
+/// ```
+// SAFETY: A `&'a T` points to data which outlives `'a`, and is not moved, accessed, or
+// deallocated when the `&'a T` is moved or coerced, so the `&T` views remain valid. No operation
+// on `&&'a T` mutates the `T` value (except via `T`'s own `&T` methods, which are already
+// permitted to be called while `&T` references exist). Lastly, `&'a T` does nothing silly to
+// detect moves.
+unsafe impl<T: ?Sized> AliasableView for &T {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+/// References of any lifetime can be cloned, not only `&'static T`; since both copies borrow the
+/// same data for the same lifetime, a view obtained from one copy remains valid after the other
+/// copy is dropped.
+///
+/// # Example
+/// ```
+/// use aliasable_view::AliasableView;
+///
+/// let data = String::from("hello");
+/// let (clone, view) = {
+///     let original: &str = &data;
+///     let view: *const str = original.view();
+///     (Clone::clone(&original), view)
+///     // `original` is dropped here.
+/// };
+///
+/// // SAFETY: `clone` is a sibling clone of `original` which has not been dropped, so as per
+/// // `AliasableClone`, the view of `original` is still valid.
+/// let view = unsafe { &*view };
+/// assert_eq!(view, clone.view());
+/// ```
+// SAFETY: Cloning a `&'a T` copies the reference, and dropping a `&'a T` does nothing, so no
+// operation on a sibling clone can invalidate the `&T` views of another.
+unsafe impl<T: ?Sized> AliasableClone for &T {}
@@ -0,0 +1,192 @@
+#![expect(unsafe_code, reason = "implement unsafe variance traits")]
+
+use core::ops::{Deref, DerefMut};
+
+use variance_family::{CovariantFamily, Varying, WithLifetime};
+
+
+/// The `&'varying <Source as Deref>::Target` lifetime family, where `Source` is the `Upper`
+/// bound of the family.
+///
+/// This is a convenient [`AliasableView::View`] family for pointer types like `Rc<T>` and
+/// `Arc<T>`, where `Source` is the implementor. Unlike `VaryingRef<Unvarying<T>>`, it does not
+/// require `T: 'static`, as `Source: 'varying` already implies `Source::Target: 'varying`.
+///
+/// This lifetime family is covariant over `'varying`, and is never contravariant over
+/// `'varying`.
+///
+/// Note that this type itself is just a marker ZST for the family.
+///
+/// # Example
+/// ```
+/// # // `Rc<T>` only implements `AliasableView` with the `alloc` feature.
+/// # #[cfg(feature = "alloc")] {
+/// use std::rc::Rc;
+/// use aliasable_view::AliasableView;
+///
+/// fn first_word<'a>(text: &'a Rc<&str>) -> &'a str {
+///     let view: &'a &str = text.view();
+///     view.split(' ').next().unwrap_or_default()
+/// }
+///
+/// assert_eq!(first_word(&Rc::new("hello world")), "hello");
+/// # }
+/// ```
+///
+/// [`AliasableView::View`]: crate::AliasableView::View
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DerefView;
+
+impl<'varying, Source> WithLifetime<'varying, '_, Source> for DerefView
+where
+    Source: ?Sized + Deref,
+{
+    type Is = &'varying Source::Target;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and
+//   `&'varying <Source as Deref>::Target` is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Source> CovariantFamily<'lower, Source> for DerefView
+where
+    Source: ?Sized + Deref,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Source, Self>,
+    ) -> Varying<'s, 'lower, Source, Self>
+    where
+        Source: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Source, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Source, Self>,
+    ) -> &'r Varying<'s, 'lower, Source, Self>
+    where
+        Source: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Source, Self>: 'r,
+        Varying<'s, 'lower, Source, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `&'varying <Source as Deref>::Target` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
+
+
+/// The `&'varying mut <Source as Deref>::Target` lifetime family, where `Source` is the `Upper`
+/// bound of the family.
+///
+/// This is the mutable counterpart of [`DerefView`], and is a convenient
+/// [`AliasableViewMut::ViewMut`] family for pointer types like [`AliasableRefMut<'_, T>`], where
+/// `Source` is the implementor. Unlike `VaryingRefMut<Unvarying<T>>`, it does not require
+/// `T: 'static`.
+///
+/// Since `<Source as Deref>::Target` is fixed by the `Upper` bound, this lifetime family is
+/// covariant over `'varying`. It is never contravariant over `'varying`.
+///
+/// Note that this type itself is just a marker ZST for the family.
+///
+/// # Example
+/// ```
+/// use aliasable_view::{AliasableRefMut, AliasableViewMut};
+///
+/// fn push_world<'a>(text: &'a mut AliasableRefMut<'_, String>) -> &'a mut String {
+///     let view: &'a mut String = text.view_mut();
+///     view.push_str(" world");
+///     view
+/// }
+///
+/// let mut data = String::from("hello");
+/// assert_eq!(push_world(&mut AliasableRefMut::from_mut(&mut data)), "hello world");
+/// ```
+///
+/// [`AliasableViewMut::ViewMut`]: crate::AliasableViewMut::ViewMut
+/// [`AliasableRefMut<'_, T>`]: crate::AliasableRefMut
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DerefViewMut;
+
+impl<'varying, Source> WithLifetime<'varying, '_, Source> for DerefViewMut
+where
+    Source: ?Sized + DerefMut,
+{
+    type Is = &'varying mut Source::Target;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and
+//   `&'varying mut <Source as Deref>::Target` is covariant over `'varying`, since the pointee
+//   type does not depend on `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Source> CovariantFamily<'lower, Source> for DerefViewMut
+where
+    Source: ?Sized + DerefMut,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Source, Self>,
+    ) -> Varying<'s, 'lower, Source, Self>
+    where
+        Source: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Source, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Source, Self>,
+    ) -> &'r Varying<'s, 'lower, Source, Self>
+    where
+        Source: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Source, Self>: 'r,
+        Varying<'s, 'lower, Source, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `&'varying mut <Source as Deref>::Target` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
@@ -27,6 +27,7 @@ extern crate std;
 
 
 mod traits;
+mod deref_view;
 mod aliasable;
 mod core_impls;
 
@@ -38,8 +39,11 @@ mod std_impls;
 
 mod other_impls;
 
+#[cfg(feature = "alloc")]
+pub use self::aliasable::AliasableBox;
 pub use self::{
-    aliasable::AliasableRefMut,
+    aliasable::{AliasableRefMut, AliasableSliceIterMut},
+    deref_view::{DerefView, DerefViewMut},
     traits::{
         AliasableClone, AliasableView, AliasableViewMut,
         IntoAliasable, IntoAliasableMut, View, ViewMut,
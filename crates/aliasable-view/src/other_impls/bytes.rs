@@ -0,0 +1,71 @@
+#![expect(unsafe_code, reason = "implement unsafe marker traits")]
+
+use bytes::{Bytes, BytesMut};
+
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableClone, AliasableView, View};
+
+
+// SAFETY: The bytes of a `Bytes` are stored in static memory or in a heap allocation (possibly
+// owned by some other value), never inline in the `Bytes` itself, and they are not moved,
+// mutated, or deallocated when the `Bytes` is moved or coerced, so the `&[u8]` views remain
+// valid. Operations on `&Bytes` (including `Bytes::clone`, which may move the bookkeeping of a
+// vector-backed `Bytes` into a new shared allocation) never move or mutate the bytes themselves.
+// Lastly, `Bytes` does nothing silly to detect moves.
+unsafe impl AliasableView for Bytes {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+/// A view of a `Bytes` remains valid for as long as any clone of that `Bytes` is alive, provided
+/// that none of the clones are mutated or consumed (as with `Bytes::truncate` or
+/// `Bytes::try_into_mut`).
+///
+/// # Example
+/// ```
+/// use aliasable_view::AliasableView;
+/// use bytes::Bytes;
+///
+/// let (clone, view) = {
+///     let original = Bytes::from(vec![1, 2, 3]);
+///     let view: *const [u8] = original.view();
+///     (original.clone(), view)
+///     // `original` is dropped here.
+/// };
+///
+/// // SAFETY: `clone` is a sibling clone of `original` which has not been dropped, so as per
+/// // `AliasableClone`, the view of `original` is still valid.
+/// let view = unsafe { &*view };
+/// assert_eq!(view, [1, 2, 3]);
+/// assert_eq!(clone, [1, 2, 3][..]);
+/// ```
+// SAFETY: Cloning a `Bytes` never copies or moves its bytes; static bytes are shared as-is, and
+// otherwise the clone shares ownership of the underlying storage with the original through a
+// reference count (promoting a vector-backed `Bytes` to shared storage on its first clone, if
+// needed). That storage is only released once every `Bytes` sharing it has been dropped, so
+// dropping a `Bytes` while a sibling clone has not been dropped only decrements the reference
+// count, and neither moves nor mutates the bytes.
+//
+// `AliasableClone` only requires the views to remain valid while every sibling clone is only
+// moved, coerced, accessed through a `&Bytes`, or dropped while another sibling remains; in
+// particular, no sibling is passed to `Bytes::try_into_mut` (or converted into a `BytesMut` or
+// `Vec<u8>`) in the meantime, so the last surviving sibling never reclaims the storage for
+// mutation. As noted above, operations on `&Bytes` never move or mutate the bytes.
+unsafe impl AliasableClone for Bytes {}
+
+// SAFETY: The bytes of a `BytesMut` are stored in a heap allocation, never inline in the
+// `BytesMut` itself, and they are not moved, mutated, or deallocated when the `BytesMut` is
+// moved or coerced, so the `&[u8]` views remain valid. Operations on `&BytesMut` only read the
+// bytes. Lastly, `BytesMut` does nothing silly to detect moves.
+unsafe impl AliasableView for BytesMut {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
@@ -0,0 +1,3 @@
+// Nothing is implemented for `either` yet; this keeps the optional dependency from tripping the
+// `unused_crate_dependencies` lint in the meantime.
+use either as _;
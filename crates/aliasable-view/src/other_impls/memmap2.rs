@@ -0,0 +1,57 @@
+#![expect(unsafe_code, reason = "implement unsafe marker traits")]
+
+use memmap2::{Mmap, MmapMut};
+
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableView, View};
+
+
+/// The mapped bytes can be viewed while the `Mmap` is moved, for instance to cache references
+/// to tokens parsed out of a memory-mapped file alongside the mapping.
+///
+/// # Example
+/// ```
+/// use aliasable_view::AliasableView;
+/// use memmap2::MmapOptions;
+///
+/// # // Miri cannot emulate the `mprotect` call made by `make_read_only`.
+/// # #[cfg(not(miri))] {
+/// let mut anonymous = MmapOptions::new().len(5).map_anon().unwrap();
+/// anonymous.copy_from_slice(b"hello");
+/// let mapped = anonymous.make_read_only().unwrap();
+///
+/// let view: &[u8] = mapped.view();
+/// assert_eq!(view, b"hello");
+/// # }
+/// ```
+// SAFETY: The bytes of an `Mmap` are in a memory mapping at a fixed address chosen by the
+// operating system, never inline in the `Mmap` itself, and they are not moved, mutated, or
+// unmapped when the `Mmap` is moved or coerced, so the `&[u8]` views remain valid. Operations on
+// `&Mmap` never write to or remap the mapping. Lastly, `Mmap` does nothing silly to detect moves.
+//
+// (Concurrent modification of a file-backed mapping by other processes is outside of Rust's
+// control; the `unsafe` constructors of `memmap2` already require callers to prevent it.)
+//
+// `Mmap` is not `Clone`, so `AliasableClone` is not implemented.
+unsafe impl AliasableView for Mmap {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
+
+// SAFETY: The bytes of an `MmapMut` are in a memory mapping at a fixed address chosen by the
+// operating system, never inline in the `MmapMut` itself, and they are not moved, mutated, or
+// unmapped when the `MmapMut` is moved or coerced, so the `&[u8]` views remain valid. Operations
+// on `&MmapMut` (such as `MmapMut::flush`) never write to or remap the mapping. Lastly, `MmapMut`
+// does nothing silly to detect moves.
+unsafe impl AliasableView for MmapMut {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
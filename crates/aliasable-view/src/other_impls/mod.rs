@@ -1,2 +1,6 @@
+#[cfg(feature = "bytes")]
+mod bytes;
 #[cfg(feature = "either")]
 mod either;
+#[cfg(feature = "memmap2")]
+mod memmap2;
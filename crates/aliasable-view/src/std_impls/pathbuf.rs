@@ -0,0 +1,33 @@
+#![expect(unsafe_code, reason = "implement unsafe marker traits")]
+
+use std::path::PathBuf;
+
+use crate::deref_view::DerefView;
+use crate::traits::{AliasableView, View};
+
+
+/// The `&Path` view can be used to cache parsed components of the path alongside the `PathBuf`.
+///
+/// # Example
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use aliasable_view::AliasableView;
+///
+/// let path = PathBuf::from("/usr/lib/libfoo.so");
+/// let view: &Path = path.view();
+/// assert_eq!(view.file_name().and_then(|name| name.to_str()), Some("libfoo.so"));
+/// ```
+// SAFETY: The bytes of a `PathBuf` are stored in the heap buffer of its inner `OsString`, which
+// is a `Vec<u8>` (or a similar byte buffer, depending on the platform), never inline in the
+// `PathBuf` itself, and they are not moved, mutated, or deallocated when the `PathBuf` is moved
+// or coerced, so the `&Path` views remain valid. As noted in the guidelines of `AliasableView`,
+// `Vec<u8>` does not currently assert exclusive access over its buffer when moved. Operations on
+// `&PathBuf` only read the path. Lastly, `PathBuf` does nothing silly to detect moves.
+unsafe impl AliasableView for PathBuf {
+    type View = DerefView;
+
+    #[inline]
+    fn view(&self) -> View<'_, Self> {
+        self
+    }
+}
@@ -1,3 +1,5 @@
+#![expect(unsafe_code, reason = "declare unsafe marker traits")]
+
 use variance_family::{LendFamily, Varying};
 
 
@@ -124,6 +126,17 @@ pub type ViewMut<'a, T> = Varying<'a, 'a, T, <T as AliasableViewMut>::ViewMut>;
 ///   For instance, if `Self` is similar to `&'a T` or [`AliasableRefMut<'a, T>`], then views of
 ///   `Self` can soundly contain references of lifetime `'a` (or other pointers guaranteed to be
 ///   valid for lifetime `'a`) to that `T` referenced by `Self`.
+/// - Small-value-optimized containers (like those of `smallbox` or `smallvec`) cannot implement
+///   this trait, as their data is stored inline whenever it fits, and moving the container then
+///   moves the data. Tracking moves at runtime is not an option, per the bullet point below about
+///   checking the address of `&Self`, and pinning the container would rule out the moves this
+///   trait exists to permit. In `no_alloc` contexts, the data should instead be placed in storage
+///   which outlives `Self` (such as a `static` buffer or a caller-provided arena) and referenced
+///   through an [`AliasableRefMut<'a, T>`] or `&'a T`.
+/// - For the same reason, interior-mutability containers like `RefCell<T>` cannot implement this
+///   trait, even though their guards (such as [`cell::Ref<'a, T>`]) look like views: the guarded
+///   value is stored inline. Place the cell behind a pointer instead; the `&RefCell<T>` view of
+///   an `Rc<RefCell<T>>` or `AliasableBox<RefCell<T>>` can be borrowed as usual.
 /// - Don't check the address of `&Self` to decide whether old views of `Self` should be
 ///   invalidated. Don't try to detect whether a by-value coercion occurred (which would also
 ///   move the source `Self`) to decide whether old views of `Self` should be invalidated.
@@ -182,7 +195,7 @@ pub type ViewMut<'a, T> = Varying<'a, 'a, T, <T as AliasableViewMut>::ViewMut>;
 /// [`cell::Ref<'a, T>`]: core::cell::Ref
 /// [`cell::RefMut<'a, T>`]: core::cell::RefMut
 /// [`UnsafeCell`]: core::cell::UnsafeCell
-/// [`AliasableRefMut<'a, T>`]: crate::data_source::aliasable::AliasableRefMut
+/// [`AliasableRefMut<'a, T>`]: crate::AliasableRefMut
 /// [`Self::View`]: AliasableView::View
 /// [`AliasableDeref`]: https://docs.rs/aliasable_deref_trait/1.0.0/aliasable_deref_trait/trait.AliasableDeref.html
 /// [`StableDeref`]: https://docs.rs/stable_deref_trait/1.2.1/stable_deref_trait/trait.StableDeref.html
@@ -347,7 +360,7 @@ pub unsafe trait AliasableView {
 /// [`cell::Ref<'a, T>`]: core::cell::Ref
 /// [`cell::RefMut<'a, T>`]: core::cell::RefMut
 /// [`UnsafeCell`]: core::cell::UnsafeCell
-/// [`AliasableRefMut<'a, T>`]: crate::data_source::aliasable::AliasableRefMut
+/// [`AliasableRefMut<'a, T>`]: crate::AliasableRefMut
 /// [`Self::ViewMut`]: AliasableViewMut::ViewMut
 pub unsafe trait AliasableViewMut: AliasableView {
     type ViewMut: LendFamily<Self>;
@@ -409,6 +422,20 @@ pub unsafe trait AliasableViewMut: AliasableView {
 /// count as a sibling clone which has not and will never be dropped (unless, for example, that
 /// `sibling` is unsafely recovered from some raw form and is later dropped).
 ///
+/// ## Operations on sibling clones
+/// The above guarantees only apply while, starting from when a view is obtained up to when it is
+/// used, every sibling clone of the view's source `Self` value is only moved, coerced, accessed
+/// through a `&Self` value (for views obtained via [`AliasableViewMut::view_mut`], only moved or
+/// coerced), or dropped while at least one other sibling clone has not been dropped.
+///
+/// Other operations on a sibling clone, namely those taking it by `&mut Self` or by value (aside
+/// from dropping it), may invalidate the views of every sibling. For example, once all but one
+/// sibling clone of an `Rc<T>` have been dropped, `Rc::get_mut` and `Rc::make_mut` can provide a
+/// `&mut T`, and `Rc::try_unwrap` and `Rc::into_inner` can move the `T` value out of its
+/// allocation. Even while other siblings remain, `Rc::make_mut` detaches the sibling it is applied
+/// to onto a fresh copy of the `T` value, after which that sibling no longer keeps the original
+/// allocation (and the views into it) alive.
+///
 /// ## `AliasableClone + AliasableViewMut`
 /// Note that mutating one sibling clone is not permitted to invalidate views of other siblings
 /// (as that would be unsound, even for entirely safe Rust not making use of the lifetime
@@ -0,0 +1,171 @@
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+
+use variance_family::LendFamily;
+
+use crate::slot::SelfRefSlot;
+use crate::uninhabited_ref::{NeverExclusiveRef, NeverSharedRef};
+use super::EraseSelfRef;
+
+
+/// Compose two [`EraseSelfRef`] implementations.
+///
+/// A [`SelfRefSlot`] is first erased with `E1`, and the resulting `E1` value is then placed in a
+/// [`NoRef`] slot and erased with `E2`.
+///
+/// Since the intermediate `NoRef` slot does not use its `'varying` lifetime at all, the outer
+/// erasure never imposes any additional validity or soundness burdens. `E2` is required to use
+/// the same [`Upper`] bound as `E1`.
+///
+/// # Example
+/// ```
+/// use attached_ref::{
+///     DoubleErase, EraseSelfRef, LifetimeErase, NeverExclusiveRef, NeverSharedRef, SelfRefSlot,
+/// };
+/// use variance_family::VaryingRef;
+///
+/// type Inner = LifetimeErase<'static, usize, VaryingRef<str>, NeverExclusiveRef>;
+/// type Outer = LifetimeErase<'static, Inner, NeverSharedRef, NeverExclusiveRef>;
+/// type Erased = DoubleErase<Inner, Outer>;
+///
+/// let source = String::from("hello world");
+///
+/// // SAFETY: dropping a `&str` or a `usize` is always sound.
+/// let erased = unsafe { Erased::erase(SelfRefSlot::SharedRef(&source[..5])) };
+/// // SAFETY: `source` is not moved, mutated, or dropped while the unerased slots are used, so
+/// // the `&str` in them is not dangling.
+/// let mut erased = unsafe { Erased::erase(Erased::unerase(erased)) };
+/// let slot = unsafe { Erased::unerase_ref(&erased) };
+/// assert!(matches!(slot, SelfRefSlot::SharedRef(shared) if *shared == "hello"));
+///
+/// // SAFETY: as above.
+/// *unsafe { Erased::unerase_mut(&mut erased) } = SelfRefSlot::NoRef(source.len());
+/// let slot = unsafe { Erased::unerase(erased) };
+/// assert!(matches!(slot, SelfRefSlot::NoRef(11)));
+/// ```
+///
+/// [`NoRef`]: SelfRefSlot::NoRef
+/// [`Upper`]: EraseSelfRef::Upper
+pub struct DoubleErase<E1, E2> {
+    /// # Safety Invariant
+    /// `outer` must have been returned by `E2::erase` when given a `SelfRefSlot::NoRef` slot
+    /// containing a value returned by `E1::erase`.
+    outer:  E2,
+    _inner: PhantomData<E1>,
+}
+
+// SAFETY: Write `erase1`, `unerase1`, and so on for the methods of `E1`, and likewise for `E2`.
+// By the safety invariant, every `Self` value wraps `erase2(NoRef(erase1(slot)))` for some
+// `slot`, up to lifetimes. Then, relying on the correctness of `E1` and `E2` (and noting that the
+// only inhabited variant of the intermediate slots is `NoRef`):
+// - `unerase(erase(slot))` is `unerase1(inner)` where `NoRef(inner)` is
+//   `unerase2(erase2(NoRef(erase1(slot))))`, that is, `inner` is `erase1(slot)`; and
+//   `unerase1(erase1(slot))` is `slot`.
+// - `erase(unerase(erased))`, where `erased` wraps `outer`, is `erase2(NoRef(erase1(x)))` with
+//   `x` being `unerase1(inner)` and `NoRef(inner)` being `unerase2(outer)`. Since
+//   `erase1(unerase1(inner))` is `inner`, this is `erase2(unerase2(outer))`, which is `outer`.
+// - `unerase_ref(&erase(slot))` is `unerase_ref1(inner)`, where `&NoRef(inner)` is
+//   `unerase_ref2(&erase2(NoRef(erase1(slot))))`, that is, `&NoRef(erase1(slot))`; and
+//   `unerase_ref1(&erase1(slot))` is `&slot`.
+// - `unerase_mut(&mut erase(slot))` is `&mut slot` by the same argument as `unerase_ref`, using
+//   `unerase_mut1` and `unerase_mut2` instead.
+//
+// `DoubleErase` has no destructor of its own, so dropping `Self` drops `outer`. The destructor
+// of `E2` may unerase `outer` to a `NoRef` slot for a lifetime within its `Drop::drop` body and
+// drop it, which drops the `E1` value returned by `erase1`. The destructor of `E1` may in turn
+// unerase that to a slot of `'within_drop_function` lifetime (even more limited, as it is
+// nested within the former drop) and drop it. `Self::erase` places the burden of that lifetime
+// on its caller, exactly as `E1::erase` requires; `NoRef` slots never use their lifetime, so
+// `E2::erase` imposes no further burden. No global state is used.
+unsafe impl<N, S, E, E1, E2> EraseSelfRef<N, S, E> for DoubleErase<E1, E2>
+where
+    S: LendFamily<E1::Upper>,
+    E: LendFamily<E1::Upper>,
+    E1: EraseSelfRef<N, S, E>,
+    E2: EraseSelfRef<E1, NeverSharedRef, NeverExclusiveRef, Upper = E1::Upper>,
+{
+    type Upper = E1::Upper;
+
+    unsafe fn erase(slot: SelfRefSlot<'_, N, S, E, Self::Upper>) -> Self {
+        // SAFETY: the returned `E1` value is only dropped when the outer slot is dropped, which
+        // is when `Self` is dropped. The caller upholds the requirements for dropping `Self`.
+        let inner = unsafe { E1::erase(slot) };
+        // SAFETY: a `NoRef` slot does not use its `'varying` lifetime at all, so dropping it is
+        // sound whenever dropping `inner` is sound.
+        let outer = unsafe { E2::erase(SelfRefSlot::NoRef(inner)) };
+
+        Self {
+            // SAFETY INVARIANT: `outer` was returned by `E2::erase` with an `E1::erase` value.
+            outer,
+            _inner: PhantomData,
+        }
+    }
+
+    unsafe fn unerase<'varying: 'varying>(
+        slot: Self,
+    ) -> SelfRefSlot<'varying, N, S, E, Self::Upper>
+    where
+        Self::Upper: 'varying,
+    {
+        // SAFETY: a `NoRef` slot does not use its `'varying` lifetime at all, so any lifetime
+        // is sound to use.
+        let outer: SelfRefSlot<'varying, E1, NeverSharedRef, NeverExclusiveRef, Self::Upper> =
+            unsafe { E2::unerase(slot.outer) };
+        let SelfRefSlot::NoRef(inner) = outer;
+
+        // SAFETY: by the safety invariant, `inner` was returned by `E1::erase`. The caller
+        // upholds the requirements for the `'varying` lifetime.
+        unsafe { E1::unerase(inner) }
+    }
+
+    unsafe fn unerase_ref<'varying: 'varying>(
+        slot: &Self,
+    ) -> &SelfRefSlot<'varying, N, S, E, Self::Upper>
+    where
+        Self::Upper: 'varying,
+    {
+        // SAFETY: a `NoRef` slot does not use its `'varying` lifetime at all, so any lifetime
+        // is sound to use.
+        let outer: &SelfRefSlot<'varying, E1, NeverSharedRef, NeverExclusiveRef, Self::Upper> =
+            unsafe { E2::unerase_ref(&slot.outer) };
+        let inner = match outer {
+            SelfRefSlot::NoRef(inner) => inner,
+            #[expect(clippy::uninhabited_references, reason = "this arm is unreachable")]
+            SelfRefSlot::SharedRef(never) | SelfRefSlot::ExclusiveRef(never) => match *never {},
+        };
+
+        // SAFETY: by the safety invariant, `inner` was returned by `E1::erase`. The caller
+        // upholds the requirements for the `'varying` lifetime.
+        unsafe { E1::unerase_ref(inner) }
+    }
+
+    unsafe fn unerase_mut<'varying: 'varying>(
+        slot: &mut Self,
+    ) -> &mut SelfRefSlot<'varying, N, S, E, Self::Upper>
+    where
+        Self::Upper: 'varying,
+    {
+        // SAFETY: a `NoRef` slot does not use its `'varying` lifetime at all, so any lifetime
+        // is sound to use.
+        let outer: &mut SelfRefSlot<'varying, E1, NeverSharedRef, NeverExclusiveRef, Self::Upper> =
+            unsafe { E2::unerase_mut(&mut slot.outer) };
+        let inner = match outer {
+            SelfRefSlot::NoRef(inner) => inner,
+            #[expect(clippy::uninhabited_references, reason = "this arm is unreachable")]
+            SelfRefSlot::SharedRef(never) | SelfRefSlot::ExclusiveRef(never) => match *never {},
+        };
+
+        // SAFETY: by the safety invariant, `inner` was returned by `E1::erase`. The caller
+        // upholds the requirements for the `'varying` lifetime.
+        unsafe { E1::unerase_mut(inner) }
+    }
+}
+
+impl<E1, E2> Debug for DoubleErase<E1, E2> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        // Debugging the inner value would require `unsafe`.
+        f.debug_struct("DoubleErase").finish_non_exhaustive()
+    }
+}
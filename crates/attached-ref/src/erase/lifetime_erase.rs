@@ -3,12 +3,54 @@ use core::{
     mem::{ManuallyDrop, MaybeUninit},
 };
 
-use variance_family::{CovariantFamily, LendFamily, Varying};
+use variance_family::LendFamily;
 
 use crate::slot::SelfRefSlot;
 use super::EraseSelfRef;
 
 
+/// Erase the `'varying` lifetime of a [`SelfRefSlot`] by storing it inline with an `'erased`
+/// lifetime, which is never exposed.
+///
+/// The `'erased` lifetime is used as the [`Upper`] bound of the slot; see [`DefaultErase`] for
+/// the common case of `'erased` being `'static`.
+///
+/// # Examples
+/// Erasing and then unerasing a slot round-trips it, and the references returned by
+/// [`unerase_ref`] and [`unerase_mut`] point to the same slot.
+/// ```
+/// use attached_ref::{
+///     EraseSelfRef, LifetimeErase, NeverExclusiveRef, NeverSharedRef, SelfRefSlot,
+/// };
+/// use variance_family::VaryingRef;
+///
+/// type NoRefErase = LifetimeErase<'static, u32, NeverSharedRef, NeverExclusiveRef>;
+///
+/// let slot = SelfRefSlot::NoRef(5);
+/// // SAFETY: dropping a `u32` is always sound, and the slot has no references.
+/// let mut erased = unsafe { NoRefErase::erase(slot) };
+/// // SAFETY: the slot has no references, so any `'varying` lifetime is valid.
+/// unsafe {
+///     let shared: *const _ = NoRefErase::unerase_ref(&erased);
+///     let exclusive: *const _ = NoRefErase::unerase_mut(&mut erased);
+///     assert_eq!(shared, exclusive);
+///     assert_eq!(NoRefErase::unerase(erased), slot);
+/// }
+///
+/// type SharedErase = LifetimeErase<'static, (), VaryingRef<str>, NeverExclusiveRef>;
+///
+/// let source = String::from("hello");
+/// let slot = SelfRefSlot::SharedRef(source.as_str());
+/// // SAFETY: dropping a `&str` is always sound.
+/// let erased = unsafe { SharedErase::erase(slot) };
+/// // SAFETY: `source` is not moved, mutated, or dropped while the returned slot is used.
+/// assert_eq!(unsafe { SharedErase::unerase(erased) }, slot);
+/// ```
+///
+/// [`Upper`]: EraseSelfRef::Upper
+/// [`DefaultErase`]: crate::DefaultErase
+/// [`unerase_ref`]: EraseSelfRef::unerase_ref
+/// [`unerase_mut`]: EraseSelfRef::unerase_mut
 pub struct LifetimeErase<'erased, N, S, E>
 where
     S: LendFamily<&'erased ()>,
@@ -34,10 +76,8 @@ where
 // do what they say.
 unsafe impl<'erased, N, S, E> EraseSelfRef<N, S, E> for LifetimeErase<'erased, N, S, E>
 where
-    S: for<'lower> CovariantFamily<'lower, &'erased ()>,
-    for<'lower, 'varying> Varying<'varying, 'lower, &'erased (), S>: Sized,
-    E: for<'lower> CovariantFamily<'lower, &'erased ()>,
-    for<'lower, 'varying> Varying<'varying, 'lower, &'erased (), E>: Sized,
+    S: LendFamily<&'erased ()>,
+    E: LendFamily<&'erased ()>,
 {
     type Upper = &'erased ();
 
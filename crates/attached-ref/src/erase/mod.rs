@@ -3,13 +3,14 @@
 mod lifetime_erase;
 mod layout_erase;
 mod heap_erase;
+mod double_erase;
 
 
 use variance_family::LendFamily;
 
 use crate::slot::SelfRefSlot;
 
-pub use self::lifetime_erase::LifetimeErase;
+pub use self::{double_erase::DoubleErase, lifetime_erase::LifetimeErase};
 
 
 /// A good default implementation for [`EraseSelfRef`].
@@ -23,6 +24,24 @@ pub use self::lifetime_erase::LifetimeErase;
 /// non-`'static` lifetime `'a`. However, it is sufficient for most common cases.
 ///
 /// Turn to other options only if [`DefaultErase`] is not sufficient.
+///
+/// # Example
+/// ```
+/// use attached_ref::{DefaultErase, EraseSelfRef, NeverExclusiveRef, SelfRefSlot};
+/// use variance_family::VaryingRef;
+///
+/// type Erased = DefaultErase<(), VaryingRef<str>, NeverExclusiveRef>;
+///
+/// let source = String::from("hello world");
+/// let slot = SelfRefSlot::SharedRef(&source[..5]);
+///
+/// // SAFETY: dropping a `&str` is always sound.
+/// let erased = unsafe { Erased::erase(slot) };
+/// // SAFETY: `source` is not moved, mutated, or dropped while the returned slot is used,
+/// // so the `&str` in the slot is not dangling.
+/// let slot = unsafe { Erased::unerase_ref(&erased) };
+/// assert_eq!(slot.as_shared_ref(), Some(&"hello"));
+/// ```
 pub type DefaultErase<N, S, E> = LifetimeErase<'static, N, S, E>;
 
 
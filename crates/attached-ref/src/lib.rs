@@ -12,20 +12,19 @@
 
 #![no_std]
 
-#[cfg(feature = "alloc")]
-extern crate alloc;
-
 mod erase;
 mod variance;
 mod uninhabited_ref;
 
 mod slot;
+mod slot_builder;
 mod attached_ref_slot;
 
 
 pub use self::{
-    erase::{DefaultErase, EraseSelfRef, LifetimeErase},
+    erase::{DefaultErase, DoubleErase, EraseSelfRef, LifetimeErase},
     slot::SelfRefSlot,
+    slot_builder::SelfRefSlotBuilder,
     uninhabited_ref::{NeverExclusiveRef, NeverNoRef, NeverSharedRef},
     variance::{Covariant, DataBound, DataVariance, Invariant},
 };
@@ -1,7 +1,14 @@
-use core::fmt::{Debug, Formatter, Result as FmtResult};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::{self, Once},
+};
 
 use variance_family::WithLifetime;
 
+use crate::uninhabited_ref::{NeverExclusiveRef, NeverNoRef, NeverSharedRef};
+
 
 pub enum SelfRefSlot<'varying, N, S, E, Upper>
 where
@@ -14,6 +21,210 @@ where
     ExclusiveRef(E::Is),
 }
 
+impl<'varying, N, S, E, Upper> SelfRefSlot<'varying, N, S, E, Upper>
+where
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    /// The index of the variant of this slot, in declaration order.
+    ///
+    /// Used to compare and hash slots of different variants.
+    #[inline]
+    #[must_use]
+    const fn variant_index(&self) -> u8 {
+        match self {
+            Self::NoRef(_) => 0,
+            Self::SharedRef(_) => 1,
+            Self::ExclusiveRef(_) => 2,
+        }
+    }
+
+    /// Fallibly transform the value of a [`NoRef`] slot, leaving other variants unchanged.
+    ///
+    /// # Errors
+    /// If `f` fails, the slot is consumed and only the error is returned. Returning the
+    /// original slot alongside the error is not possible in general, since `f` takes ownership
+    /// of the value in the slot; if the value needs to be recovered, `f` should return it as
+    /// part of its error.
+    ///
+    /// [`NoRef`]: SelfRefSlot::NoRef
+    pub fn try_map_no_ref<N2, Error, F>(
+        self,
+        f: F,
+    ) -> Result<SelfRefSlot<'varying, N2, S, E, Upper>, Error>
+    where
+        F: FnOnce(N) -> Result<N2, Error>,
+    {
+        Ok(match self {
+            Self::NoRef(no_ref) => SelfRefSlot::NoRef(f(no_ref)?),
+            Self::SharedRef(shared_ref) => SelfRefSlot::SharedRef(shared_ref),
+            Self::ExclusiveRef(exclusive_ref) => SelfRefSlot::ExclusiveRef(exclusive_ref),
+        })
+    }
+
+    /// Fallibly transform the value of a [`SharedRef`] slot, leaving other variants unchanged.
+    ///
+    /// # Errors
+    /// If `f` fails, the slot is consumed and only the error is returned. See
+    /// [`try_map_no_ref`] for the reasoning.
+    ///
+    /// [`SharedRef`]: SelfRefSlot::SharedRef
+    /// [`try_map_no_ref`]: SelfRefSlot::try_map_no_ref
+    pub fn try_map_shared_ref<S2, Error, F>(
+        self,
+        f: F,
+    ) -> Result<SelfRefSlot<'varying, N, S2, E, Upper>, Error>
+    where
+        S2: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+        F: FnOnce(S::Is) -> Result<S2::Is, Error>,
+    {
+        Ok(match self {
+            Self::NoRef(no_ref) => SelfRefSlot::NoRef(no_ref),
+            Self::SharedRef(shared_ref) => SelfRefSlot::SharedRef(f(shared_ref)?),
+            Self::ExclusiveRef(exclusive_ref) => SelfRefSlot::ExclusiveRef(exclusive_ref),
+        })
+    }
+
+    /// Fallibly transform the value of an [`ExclusiveRef`] slot, leaving other variants
+    /// unchanged.
+    ///
+    /// # Errors
+    /// If `f` fails, the slot is consumed and only the error is returned. See
+    /// [`try_map_no_ref`] for the reasoning.
+    ///
+    /// [`ExclusiveRef`]: SelfRefSlot::ExclusiveRef
+    /// [`try_map_no_ref`]: SelfRefSlot::try_map_no_ref
+    pub fn try_map_exclusive_ref<E2, Error, F>(
+        self,
+        f: F,
+    ) -> Result<SelfRefSlot<'varying, N, S, E2, Upper>, Error>
+    where
+        E2: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+        F: FnOnce(E::Is) -> Result<E2::Is, Error>,
+    {
+        Ok(match self {
+            Self::NoRef(no_ref) => SelfRefSlot::NoRef(no_ref),
+            Self::SharedRef(shared_ref) => SelfRefSlot::SharedRef(shared_ref),
+            Self::ExclusiveRef(exclusive_ref) => SelfRefSlot::ExclusiveRef(f(exclusive_ref)?),
+        })
+    }
+
+    /// Get a reference to the value of a [`NoRef`] slot, if this is a [`NoRef`] slot.
+    ///
+    /// [`NoRef`]: SelfRefSlot::NoRef
+    #[inline]
+    #[must_use]
+    pub const fn as_no_ref(&self) -> Option<&N> {
+        match self {
+            Self::NoRef(no_ref) => Some(no_ref),
+            Self::SharedRef(_) | Self::ExclusiveRef(_) => None,
+        }
+    }
+
+    /// Get a reference to the value of a [`SharedRef`] slot, if this is a [`SharedRef`] slot.
+    ///
+    /// [`SharedRef`]: SelfRefSlot::SharedRef
+    #[inline]
+    #[must_use]
+    pub const fn as_shared_ref(&self) -> Option<&S::Is> {
+        match self {
+            Self::SharedRef(shared_ref) => Some(shared_ref),
+            Self::NoRef(_) | Self::ExclusiveRef(_) => None,
+        }
+    }
+
+    /// Get a reference to the value of an [`ExclusiveRef`] slot, if this is an [`ExclusiveRef`]
+    /// slot.
+    ///
+    /// [`ExclusiveRef`]: SelfRefSlot::ExclusiveRef
+    #[inline]
+    #[must_use]
+    pub const fn as_exclusive_ref(&self) -> Option<&E::Is> {
+        match self {
+            Self::ExclusiveRef(exclusive_ref) => Some(exclusive_ref),
+            Self::NoRef(_) | Self::SharedRef(_) => None,
+        }
+    }
+
+    /// Iterate over this slot, which is yielded exactly once.
+    ///
+    /// This is mainly useful in iterator chains, such as
+    /// `slot.iter().filter_map(SelfRefSlot::as_shared_ref)`.
+    #[inline]
+    pub fn iter(&self) -> Once<&Self> {
+        iter::once(self)
+    }
+}
+
+impl<N, Upper> SelfRefSlot<'_, N, NeverSharedRef, NeverExclusiveRef, Upper>
+where
+    Upper: ?Sized,
+{
+    /// Get the value of this slot, which is necessarily a [`NoRef`] slot.
+    ///
+    /// [`NoRef`]: SelfRefSlot::NoRef
+    #[inline]
+    #[must_use]
+    pub fn into_no_ref(self) -> N {
+        match self {
+            Self::NoRef(no_ref) => no_ref,
+            Self::SharedRef(never) | Self::ExclusiveRef(never) => match never {},
+        }
+    }
+}
+
+impl<'varying, S, Upper> SelfRefSlot<'varying, NeverNoRef, S, NeverExclusiveRef, Upper>
+where
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    /// Get the value of this slot, which is necessarily a [`SharedRef`] slot.
+    ///
+    /// [`SharedRef`]: SelfRefSlot::SharedRef
+    #[inline]
+    #[must_use]
+    pub fn into_shared_ref(self) -> S::Is {
+        match self {
+            Self::SharedRef(shared_ref) => shared_ref,
+            Self::NoRef(never) => match never {},
+            Self::ExclusiveRef(never) => match never {},
+        }
+    }
+}
+
+/// Iterate over the slot, which is yielded exactly once.
+impl<'varying, N, S, E, Upper> IntoIterator for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    type Item = Self;
+    type IntoIter = Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
+/// Iterate over the slot, which is yielded exactly once.
+impl<'varying, N, S, E, Upper> IntoIterator for &SelfRefSlot<'varying, N, S, E, Upper>
+where
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    type Item = Self;
+    type IntoIter = Once<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self)
+    }
+}
+
 impl<'varying, N, S, E, Upper> Clone for SelfRefSlot<'varying, N, S, E, Upper>
 where
     N: Clone,
@@ -59,3 +270,120 @@ where
         }
     }
 }
+
+impl<'varying, N, S, E, Upper> PartialEq for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: PartialEq,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized + PartialEq>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized + PartialEq>,
+    Upper: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::NoRef(this), Self::NoRef(other)) => this == other,
+            (Self::SharedRef(this), Self::SharedRef(other)) => this == other,
+            (Self::ExclusiveRef(this), Self::ExclusiveRef(other)) => this == other,
+            _ => false,
+        }
+    }
+}
+
+impl<'varying, N, S, E, Upper> Eq for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: Eq,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized + Eq>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized + Eq>,
+    Upper: ?Sized,
+{}
+
+/// A slot is equal to an `N` value only if it is a [`NoRef`] slot holding an equal value.
+///
+/// Slots of the other variants are never equal to an `N` value; comparing them returns `false`
+/// rather than panicking. (A similar impl comparing [`SharedRef`] slots to `S::Is` values is not
+/// possible, since `S::Is` could be `N` or the slot type itself, so the impls would overlap.)
+///
+/// # Example
+/// ```
+/// use attached_ref::{NeverExclusiveRef, SelfRefSlot};
+/// use variance_family::VaryingRef;
+///
+/// type Slot<'a> = SelfRefSlot<'a, u32, VaryingRef<str>, NeverExclusiveRef, &'static ()>;
+///
+/// let no_ref: Slot<'_> = SelfRefSlot::NoRef(5);
+/// let shared_ref: Slot<'_> = SelfRefSlot::SharedRef("five");
+/// assert_eq!(no_ref, 5);
+/// assert_ne!(no_ref, 6);
+/// assert_ne!(shared_ref, 5);
+/// ```
+///
+/// [`NoRef`]: SelfRefSlot::NoRef
+/// [`SharedRef`]: SelfRefSlot::SharedRef
+impl<'varying, N, S, E, Upper> PartialEq<N> for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: PartialEq,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    fn eq(&self, other: &N) -> bool {
+        match self {
+            Self::NoRef(this) => this == other,
+            Self::SharedRef(_) | Self::ExclusiveRef(_) => false,
+        }
+    }
+}
+
+/// Slots of different variants are ordered by variant, with
+/// `NoRef < SharedRef < ExclusiveRef`.
+impl<'varying, N, S, E, Upper> PartialOrd for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: PartialOrd,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized + PartialOrd>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized + PartialOrd>,
+    Upper: ?Sized,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::NoRef(this), Self::NoRef(other)) => this.partial_cmp(other),
+            (Self::SharedRef(this), Self::SharedRef(other)) => this.partial_cmp(other),
+            (Self::ExclusiveRef(this), Self::ExclusiveRef(other)) => this.partial_cmp(other),
+            _ => self.variant_index().partial_cmp(&other.variant_index()),
+        }
+    }
+}
+
+/// Slots of different variants are ordered by variant, with
+/// `NoRef < SharedRef < ExclusiveRef`.
+impl<'varying, N, S, E, Upper> Ord for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: Ord,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized + Ord>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized + Ord>,
+    Upper: ?Sized,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::NoRef(this), Self::NoRef(other)) => this.cmp(other),
+            (Self::SharedRef(this), Self::SharedRef(other)) => this.cmp(other),
+            (Self::ExclusiveRef(this), Self::ExclusiveRef(other)) => this.cmp(other),
+            _ => self.variant_index().cmp(&other.variant_index()),
+        }
+    }
+}
+
+impl<'varying, N, S, E, Upper> Hash for SelfRefSlot<'varying, N, S, E, Upper>
+where
+    N: Hash,
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized + Hash>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized + Hash>,
+    Upper: ?Sized,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.variant_index().hash(state);
+        match self {
+            Self::NoRef(no_ref) => no_ref.hash(state),
+            Self::SharedRef(shared_ref) => shared_ref.hash(state),
+            Self::ExclusiveRef(exclusive_ref) => exclusive_ref.hash(state),
+        }
+    }
+}
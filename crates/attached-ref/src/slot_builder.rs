@@ -0,0 +1,121 @@
+use core::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+};
+
+use variance_family::WithLifetime;
+
+use crate::slot::SelfRefSlot;
+
+
+/// A zero-sized helper for constructing [`SelfRefSlot`] values without repeating their full
+/// type.
+///
+/// The `'varying` lifetime and `Upper` bound of the slots can be inferred from a reference to the
+/// source data with [`for_source`], and the `N`, `S`, and `E` families then only need to be
+/// named once for any number of slots (for instance, with a type alias).
+///
+/// # Example
+/// ```
+/// use attached_ref::{NeverExclusiveRef, SelfRefSlotBuilder};
+/// use variance_family::VaryingRef;
+///
+/// type Builder<'a> = SelfRefSlotBuilder<'a, usize, VaryingRef<str>, NeverExclusiveRef, String>;
+///
+/// let source = String::from("hello world");
+/// let builder = Builder::for_source(&source);
+///
+/// let shared = builder.shared_ref(&source[..5]);
+/// let no_ref = builder.no_ref(source.len());
+/// assert_eq!(shared.as_shared_ref(), Some(&"hello"));
+/// assert_eq!(no_ref.as_no_ref(), Some(&11));
+/// ```
+///
+/// [`for_source`]: SelfRefSlotBuilder::for_source
+pub struct SelfRefSlotBuilder<'varying, N, S, E, Upper: ?Sized> {
+    _no_ref:        PhantomData<fn() -> N>,
+    _shared_ref:    PhantomData<fn() -> S>,
+    _exclusive_ref: PhantomData<fn() -> E>,
+    _source:        PhantomData<&'varying Upper>,
+}
+
+impl<'varying, N, S, E, Upper: ?Sized> SelfRefSlotBuilder<'varying, N, S, E, Upper> {
+    /// Create a builder whose slots' lifetime and `Upper` bound are inferred from context.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            _no_ref:        PhantomData,
+            _shared_ref:    PhantomData,
+            _exclusive_ref: PhantomData,
+            _source:        PhantomData,
+        }
+    }
+
+    /// Create a builder whose slots' lifetime and `Upper` bound are inferred from a reference to
+    /// the source data.
+    #[inline]
+    #[must_use]
+    pub const fn for_source(_source: &'varying Upper) -> Self {
+        Self::new()
+    }
+}
+
+impl<'varying, N, S, E, Upper> SelfRefSlotBuilder<'varying, N, S, E, Upper>
+where
+    S: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    E: WithLifetime<'varying, 'varying, Upper, Is: Sized>,
+    Upper: ?Sized,
+{
+    /// Construct a [`NoRef`] slot.
+    ///
+    /// [`NoRef`]: SelfRefSlot::NoRef
+    #[inline]
+    #[must_use]
+    pub const fn no_ref(self, no_ref: N) -> SelfRefSlot<'varying, N, S, E, Upper> {
+        SelfRefSlot::NoRef(no_ref)
+    }
+
+    /// Construct a [`SharedRef`] slot.
+    ///
+    /// [`SharedRef`]: SelfRefSlot::SharedRef
+    #[inline]
+    #[must_use]
+    pub const fn shared_ref(self, shared_ref: S::Is) -> SelfRefSlot<'varying, N, S, E, Upper> {
+        SelfRefSlot::SharedRef(shared_ref)
+    }
+
+    /// Construct an [`ExclusiveRef`] slot.
+    ///
+    /// [`ExclusiveRef`]: SelfRefSlot::ExclusiveRef
+    #[inline]
+    #[must_use]
+    pub const fn exclusive_ref(
+        self,
+        exclusive_ref: E::Is,
+    ) -> SelfRefSlot<'varying, N, S, E, Upper> {
+        SelfRefSlot::ExclusiveRef(exclusive_ref)
+    }
+}
+
+impl<N, S, E, Upper: ?Sized> Default for SelfRefSlotBuilder<'_, N, S, E, Upper> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N, S, E, Upper: ?Sized> Clone for SelfRefSlotBuilder<'_, N, S, E, Upper> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<N, S, E, Upper: ?Sized> Copy for SelfRefSlotBuilder<'_, N, S, E, Upper> {}
+
+impl<N, S, E, Upper: ?Sized> Debug for SelfRefSlotBuilder<'_, N, S, E, Upper> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("SelfRefSlotBuilder").finish()
+    }
+}
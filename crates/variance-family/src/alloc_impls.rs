@@ -0,0 +1,861 @@
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "more_impls")]
+use alloc::collections::{BTreeMap, BinaryHeap, VecDeque, btree_map::Entry as BTreeMapEntry};
+use alloc::ffi::CString;
+#[cfg(feature = "more_impls")]
+use core::mem::{ManuallyDrop, transmute, transmute_copy};
+
+use crate::invariant_zst;
+use crate::traits::{ContravariantFamily, CovariantFamily, UnvaryingFamily, Varying, WithLifetime};
+
+
+// Note: in below safety comments, "is covariant over" or "is contravariant over" means, more
+// precisely, "is sound to covariantly (or contravariantly) cast with respect to". That is,
+// manually-proven variance (and manually-proven soundness of casts) is the relevant concern,
+// not compiler-assigned variance (and compiler-proven soundness of casts).
+
+// ================================================================
+//  Cow<'varying, T>    (VaryingCow<T>)
+// ================================================================
+
+// Safety summary:
+// - `Cow<'varying, U>` is covariant over `'varying`. Below, `T<'varying>` families are used which
+//   implement `UnvaryingFamily`, making them equivalent to `Cow<'varying, U>` for some type `U`.
+//   Unsafe transmutes aren't even needed.
+// - `Cow<'varying, T<'varying>>` is never contravariant over `'varying`.
+//
+// Note that `Cow<'varying, T<'varying>>` can hold a `<T<'varying> as ToOwned>::Owned` value, and
+// nothing constrains how the `Owned` type depends on `'varying`. Therefore, even if
+// `T<'varying>` is covariant over `'varying`, `Cow<'varying, T<'varying>>` might not be.
+
+invariant_zst!(
+    /// The `Cow<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` does not actually use `'varying` at all (making it some fixed type `U`
+    /// regardless of `'varying`), then `Cow<'varying, T<'varying>>` is covariant over `'varying`.
+    /// In particular, `VaryingCow<str>` is the family of `Cow<'varying, str>`, and the same
+    /// applies to the other types which are lifetime families of themselves (such as `CStr` and
+    /// `Path`). For any other fixed type `B`, `VaryingCow<Unvarying<B>>` is the family of
+    /// `Cow<'varying, B>`. Since `B` must outlive every `'varying` lifetime, it is effectively
+    /// required to be `'static`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::borrow::Cow;
+    /// use variance_family::{CovariantFamily, Varying};
+    /// use variance_family::borrow::VaryingCow;
+    ///
+    /// type CowStr = VaryingCow<str>;
+    ///
+    /// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), CowStr>) -> Cow<'b, str> {
+    ///     <CowStr as CovariantFamily<'b, ()>>::shorten(long)
+    /// }
+    ///
+    /// assert_eq!(shorten(Cow::Borrowed("hello")), "hello");
+    /// assert_eq!(shorten(Cow::Owned(String::from("world"))), "world");
+    /// ```
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingCow<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingCow<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: ToOwned + 'varying,
+{
+    type Is = Cow<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `T<'varying>` does not
+//   actually use `'varying` at all, so `Cow<'varying, T<'varying>>` can be treated as
+//   `Cow<'varying, U>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingCow<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    T::WithAnyLifetime: ToOwned,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `Cow<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+// ================================================================
+//  CString
+// ================================================================
+
+// Safety summary:
+// - `CString` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for CString {
+    type Is = Self;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for CString {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for CString {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  collections::BTreeMap<K, V>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `BTreeMap<K<'varying>, V<'varying>>` is covariant over `'varying` if `K<'varying>` and
+//   `V<'varying>` are covariant over `'varying`.
+// - `BTreeMap<K<'varying>, V<'varying>>` is contravariant over `'varying` if `K<'varying>` and
+//   `V<'varying>` are contravariant over `'varying`.
+//
+// Changing lifetimes cannot affect the behavior of `Ord` implementations, so the casted map
+// remains valid.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, K, V> WithLifetime<'varying, 'lower, Upper> for BTreeMap<K, V>
+where
+    Upper: ?Sized,
+    K: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    V: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = BTreeMap<K::Is, V::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `K::covariant_assertions()` and `V::covariant_assertions()` do not
+//   panic, in which case `K<'varying>` and `V<'varying>` are covariant over `'varying`, implying
+//   that `BTreeMap<K<'varying>, V<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, K, V> CovariantFamily<'lower, Upper> for BTreeMap<K, V>
+where
+    Upper: ?Sized,
+    K: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, K>: Sized,
+    V: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, V>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        K::covariant_assertions();
+        V::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `K<'l>` and `V<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `K::covariant_assertions()` and
+        // `V::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `K<'varying>` and `V<'varying>` is sound. Changing lifetimes does not change the
+        // layout of a type, so reading the `BTreeMap<K<'s>, V<'s>>` out of the `ManuallyDrop` is
+        // sound, and the source value is never dropped.
+        let dst: BTreeMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r BTreeMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+        > = long;
+        // SAFETY: we are shortening the `'l` lifetime of `K<'l>` and `V<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `K::covariant_assertions()` and
+        // `V::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `K<'varying>` and `V<'varying>` is sound.
+        let dst: &'r BTreeMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `K::contravariant_assertions()` and `V::contravariant_assertions()` do
+//   not panic, in which case `K<'varying>` and `V<'varying>` are contravariant over `'varying`,
+//   implying that `BTreeMap<K<'varying>, V<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, K, V> ContravariantFamily<'lower, Upper> for BTreeMap<K, V>
+where
+    Upper: ?Sized,
+    K: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, K>: Sized,
+    V: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, V>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        K::contravariant_assertions();
+        V::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `K<'s>` and `V<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `K::contravariant_assertions()` and
+        // `V::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `K<'varying>` and `V<'varying>` is sound. Changing lifetimes does
+        // not change the layout of a type, so reading the `BTreeMap<K<'l>, V<'l>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: BTreeMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r BTreeMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+        > = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `K<'s>` and `V<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `K::contravariant_assertions()` and
+        // `V::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `K<'varying>` and `V<'varying>` is sound.
+        let dst: &'r BTreeMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  btree_map::Entry<'varying, K, V>    (VaryingBTreeMapEntry<K, V>, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `btree_map::Entry<'varying, K2, V2>` is covariant over `'varying`, like `&'varying mut T`.
+//   Below, `K<'varying>` and `V<'varying>` families are used which implement `UnvaryingFamily`,
+//   making the family equivalent to `btree_map::Entry<'varying, K2, V2>` for some types `K2` and
+//   `V2`. Unsafe transmutes aren't even needed.
+// - `btree_map::Entry<'varying, K<'varying>, V<'varying>>` is never contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `btree_map::Entry<'varying, K<'varying>, V<'varying>>` lifetime family.
+    ///
+    /// If `K<'varying>` and `V<'varying>` do not actually use `'varying` at all (making them some
+    /// fixed types regardless of `'varying`), then this family is covariant over `'varying`.
+    /// Since an entry mutably borrows its map, the keys and values of the map cannot vary with
+    /// `'varying`, even for vacant entries. The fixed key and value types must also outlive every
+    /// `'varying` lifetime, so they are effectively required to be `'static`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingBTreeMapEntry<K, V>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, K, V> WithLifetime<'varying, 'lower, Upper>
+for VaryingBTreeMapEntry<K, V>
+where
+    Upper: ?Sized,
+    K: WithLifetime<'varying, 'lower, Upper, Is: Sized + 'varying>,
+    V: WithLifetime<'varying, 'lower, Upper, Is: Sized + 'varying>,
+{
+    type Is = BTreeMapEntry<'varying, K::Is, V::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `K<'varying>` and
+//   `V<'varying>` do not actually use `'varying` at all, so
+//   `btree_map::Entry<'varying, K<'varying>, V<'varying>>` can be treated as
+//   `btree_map::Entry<'varying, K2, V2>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, K, V> CovariantFamily<'lower, Upper> for VaryingBTreeMapEntry<K, V>
+where
+    Upper: ?Sized,
+    K: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+    for<'varying> <K as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+    V: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+    for<'varying> <V as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `btree_map::Entry<'varying, K<'varying>, V<'varying>>` is never contravariant over `'varying`.
+// It's always at best covariant, never bivariant.
+
+
+// ================================================================
+//  collections::BinaryHeap<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `BinaryHeap<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `BinaryHeap<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for BinaryHeap<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = BinaryHeap<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `BinaryHeap<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for BinaryHeap<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<BinaryHeap<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `BinaryHeap<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: BinaryHeap<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r BinaryHeap<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r BinaryHeap<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `BinaryHeap<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for BinaryHeap<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<BinaryHeap<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `BinaryHeap<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: BinaryHeap<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r BinaryHeap<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r BinaryHeap<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  collections::VecDeque<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `VecDeque<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `VecDeque<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VecDeque<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = VecDeque<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `VecDeque<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VecDeque<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<VecDeque<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `VecDeque<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: VecDeque<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r VecDeque<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r VecDeque<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `VecDeque<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for VecDeque<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<VecDeque<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `VecDeque<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: VecDeque<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r VecDeque<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r VecDeque<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
@@ -0,0 +1,80 @@
+use crate::traits::{ContravariantFamily, CovariantFamily, WithLifetime};
+
+
+/// Assert that `F<'varying>` is `T`.
+///
+/// This function does nothing; it only fails to compile if `F` does not apply `'varying` to
+/// produce a `T`. It can be called in a `const` block to check that the right family is used for
+/// some type.
+///
+/// # Example
+/// ```
+/// use variance_family::{VaryingRef, assertions::assert_family_is};
+///
+/// const { assert_family_is::<'static, 'static, (), VaryingRef<str>, &str>() };
+/// ```
+/// ```compile_fail
+/// use variance_family::{VaryingRef, assertions::assert_family_is};
+///
+/// const { assert_family_is::<'static, 'static, (), VaryingRef<str>, &[u8]>() };
+/// ```
+#[inline]
+pub const fn assert_family_is<'varying, 'lower, Upper, F, T>()
+where
+    'varying: 'lower,
+    Upper: ?Sized + 'varying,
+    F: ?Sized + WithLifetime<'varying, 'lower, Upper, Is = T>,
+    T: ?Sized,
+{}
+
+/// Assert that `F` implements [`CovariantFamily<'lower, Upper>`].
+///
+/// This function does nothing; it only fails to compile if the trait is not implemented. Note
+/// that [`CovariantFamily::covariant_assertions`] is not (and cannot be) called by this function,
+/// so casts could still fail the assertions of the family.
+///
+/// # Example
+/// ```
+/// use variance_family::{VaryingRef, assertions::assert_covariant};
+///
+/// const { assert_covariant::<'static, (), VaryingRef<str>>() };
+/// ```
+/// ```compile_fail
+/// use variance_family::{VaryingRef, assertions::assert_covariant};
+///
+/// const { assert_covariant::<'static, (), fn(VaryingRef<str>)>() };
+/// ```
+///
+/// [`CovariantFamily<'lower, Upper>`]: CovariantFamily
+#[inline]
+pub const fn assert_covariant<'lower, Upper, F>()
+where
+    Upper: ?Sized,
+    F: ?Sized + CovariantFamily<'lower, Upper>,
+{}
+
+/// Assert that `F` implements [`ContravariantFamily<'lower, Upper>`].
+///
+/// This function does nothing; it only fails to compile if the trait is not implemented. Note
+/// that [`ContravariantFamily::contravariant_assertions`] is not (and cannot be) called by this
+/// function, so casts could still fail the assertions of the family.
+///
+/// # Example
+/// ```
+/// use variance_family::{VaryingRef, assertions::assert_contravariant};
+///
+/// const { assert_contravariant::<'static, (), fn(VaryingRef<str>)>() };
+/// ```
+/// ```compile_fail
+/// use variance_family::{VaryingRef, assertions::assert_contravariant};
+///
+/// const { assert_contravariant::<'static, (), VaryingRef<str>>() };
+/// ```
+///
+/// [`ContravariantFamily<'lower, Upper>`]: ContravariantFamily
+#[inline]
+pub const fn assert_contravariant<'lower, Upper, F>()
+where
+    Upper: ?Sized,
+    F: ?Sized + ContravariantFamily<'lower, Upper>,
+{}
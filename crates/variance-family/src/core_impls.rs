@@ -0,0 +1,4613 @@
+use core::cell::{Ref, RefMut};
+#[cfg(feature = "more_impls")]
+use core::cell::{LazyCell, OnceCell};
+#[cfg(feature = "more_impls")]
+use core::cmp::Reverse;
+#[cfg(feature = "more_impls")]
+use core::fmt::Arguments;
+#[cfg(feature = "more_impls")]
+use core::iter::{Chain, Enumerate, Flatten, Peekable, Zip};
+#[cfg(feature = "more_impls")]
+use core::mem::MaybeUninit;
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, transmute, transmute_copy};
+use core::pin::Pin;
+#[cfg(feature = "more_impls")]
+use core::num::{NonZero, Saturating, Wrapping};
+#[cfg(feature = "more_impls")]
+use core::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+#[cfg(feature = "more_impls")]
+use core::slice::{Iter as SliceIter, IterMut as SliceIterMut};
+
+use crate::invariant_zst;
+#[cfg(feature = "more_impls")]
+use crate::traits::LifetimeFamily;
+use crate::traits::{ContravariantFamily, CovariantFamily, UnvaryingFamily, Varying, WithLifetime};
+
+
+// Note: in below safety comments, "is covariant over" or "is contravariant over" means, more
+// precisely, "is sound to covariantly (or contravariantly) cast with respect to". That is,
+// manually-proven variance (and manually-proven soundness of casts) is the relevant concern,
+// not compiler-assigned variance (and compiler-proven soundness of casts).
+
+// ================================================================
+//  ()
+// ================================================================
+
+// Safety summary:
+// - `()` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for () {
+    type Is = ();
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for () {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::semicolon_if_nothing_returned, reason = "mirror the other impls")]
+
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for () {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::semicolon_if_nothing_returned, reason = "mirror the other impls")]
+
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  bool, char, f32, f64, i8..=i128, isize, u8..=u128, usize
+// ================================================================
+
+// Safety summary:
+// - Each primitive type is bivariant over `'varying` (as it's entirely unused).
+
+// NOTE: for soundness, this macro should not be exported, even just within this crate.
+// It assumes that it is used with *this* crate's traits in scope (with the normal names).
+// In particular, the `unsafe impl` could be broken in other environments.
+macro_rules! lifetimeless_family {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for $ty {
+                type Is = Self;
+            }
+
+            // SAFETY:
+            // - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>`
+            //   does not actually use `'varying` at all, making it covariant over `'varying`.
+            // - No assertions are included.
+            // - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+            unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for $ty {
+                #[inline]
+                fn covariant_assertions() {}
+
+                #[inline]
+                fn shorten<'l, 's>(
+                    long: Varying<'l, 'lower, Upper, Self>,
+                ) -> Varying<'s, 'lower, Upper, Self>
+                where
+                    Upper: 'l,
+                    'l: 's,
+                    's: 'lower,
+                    for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+                {
+                    #![expect(
+                        clippy::unnecessary_safety_comment,
+                        reason = "implementation safety of method",
+                    )]
+                    // Implementation safety: implementing this with `{ long }` is always safe.
+
+                    long
+                }
+
+                #[inline]
+                fn shorten_ref<'l, 's, 'r>(
+                    long: &'r Varying<'l, 'lower, Upper, Self>,
+                ) -> &'r Varying<'s, 'lower, Upper, Self>
+                where
+                    Upper: 'l,
+                    'l: 's,
+                    's: 'lower,
+                    Varying<'l, 'lower, Upper, Self>: 'r,
+                    Varying<'s, 'lower, Upper, Self>: 'r,
+                {
+                    #![expect(
+                        clippy::unnecessary_safety_comment,
+                        reason = "implementation safety of method",
+                    )]
+                    // Implementation safety: implementing this with `{ long }` is always safe.
+
+                    long
+                }
+            }
+
+            // SAFETY:
+            // - `Self::contravariant_assertions()` is trivial and never panics, and
+            //   `Self<'varying>` does not actually use `'varying` at all, making it contravariant
+            //   over `'varying`.
+            // - No assertions are included.
+            // - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+            unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for $ty {
+                #[inline]
+                fn contravariant_assertions() {}
+
+                #[inline]
+                fn lengthen<'s, 'l>(
+                    short: Varying<'s, 'lower, Upper, Self>,
+                ) -> Varying<'l, 'lower, Upper, Self>
+                where
+                    Upper: 'l,
+                    'l: 's,
+                    's: 'lower,
+                    for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+                {
+                    #![expect(
+                        clippy::unnecessary_safety_comment,
+                        reason = "implementation safety of method",
+                    )]
+                    // Implementation safety: implementing this with `{ short }` is always safe.
+
+                    short
+                }
+
+                #[inline]
+                fn lengthen_ref<'s, 'l, 'r>(
+                    short: &'r Varying<'s, 'lower, Upper, Self>,
+                ) -> &'r Varying<'l, 'lower, Upper, Self>
+                where
+                    Upper: 'l,
+                    'l: 's,
+                    's: 'lower,
+                    Varying<'l, 'lower, Upper, Self>: 'r,
+                    Varying<'s, 'lower, Upper, Self>: 'r,
+                {
+                    #![expect(
+                        clippy::unnecessary_safety_comment,
+                        reason = "implementation safety of method",
+                    )]
+                    // Implementation safety: implementing this with `{ short }` is always safe.
+
+                    short
+                }
+            }
+        )+
+    };
+}
+
+lifetimeless_family!(
+    bool, char, f32, f64,
+    i8, i16, i32, i64, i128, isize,
+    u8, u16, u32, u64, u128, usize,
+);
+
+
+// ================================================================
+//  str
+// ================================================================
+
+// Safety summary:
+// - `str` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for str {
+    type Is = Self;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for str {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for str {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  CStr
+// ================================================================
+
+// Safety summary:
+// - `CStr` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for CStr {
+    type Is = Self;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for CStr {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for CStr {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  (T1, .., Tn)    (for arities 1..=16)
+// ================================================================
+
+// Safety summary:
+// - `(T1<'varying>, .., Tn<'varying>)` is covariant over `'varying` if each `Ti<'varying>` is
+//   covariant over `'varying`.
+// - `(T1<'varying>, .., Tn<'varying>)` is contravariant over `'varying` if each `Ti<'varying>` is
+//   contravariant over `'varying`.
+
+// NOTE: for soundness, this macro should not be exported, even just within this crate.
+// It assumes that it is used with *this* crate's traits in scope (with the normal names).
+// In particular, the `unsafe impl` could be broken in other environments.
+macro_rules! tuple_family {
+    ($($Ti:ident $ti:ident),+) => {
+        impl<'varying, 'lower, Upper, $($Ti),+> WithLifetime<'varying, 'lower, Upper>
+        for ($($Ti,)+)
+        where
+            Upper: ?Sized,
+            $(
+                $Ti: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+            )+
+        {
+            type Is = ($($Ti::Is,)+);
+        }
+
+        // SAFETY:
+        // - If `Self::covariant_assertions()` does not panic,
+        //   then `Self<'varying>` is covariant over `'varying`.
+        //
+        //   The former implies that each `Ti::covariant_assertions()` does not panic,
+        //   in which case each `Ti<'varying>` is covariant over `'varying`,
+        //   implying that `(.., Ti<'varying>, ..)` is covariant over `'varying`.
+        //
+        // - No assertions are included other than those in `Self::covariant_assertions()`.
+        // - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+        unsafe impl<'lower, Upper, $($Ti),+> CovariantFamily<'lower, Upper> for ($($Ti,)+)
+        where
+            Upper: ?Sized,
+            $(
+                $Ti: CovariantFamily<'lower, Upper>,
+                for<'varying> Varying<'varying, 'lower, Upper, $Ti>: Sized,
+            )+
+        {
+            #[inline]
+            fn covariant_assertions() {
+                $(
+                    $Ti::covariant_assertions();
+                )+
+            }
+
+            #[inline]
+            fn shorten<'l, 's>(
+                long: Varying<'l, 'lower, Upper, Self>,
+            ) -> Varying<'s, 'lower, Upper, Self>
+            where
+                Upper: 'l,
+                'l: 's,
+                's: 'lower,
+                for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+            {
+                #![expect(
+                    clippy::unnecessary_safety_comment,
+                    reason = "implementation safety of method",
+                )]
+                // Implementation safety: this is just a covariant cast of each element with,
+                // possibly, some assertions. Any possible sources of panics in each `Ti::shorten`
+                // must be included in `Ti::covariant_assertions`, which are included in
+                // `Self::covariant_assertions`.
+
+                let ($($ti,)+) = long;
+                ($($Ti::shorten($ti),)+)
+            }
+
+            #[inline]
+            fn shorten_ref<'l, 's, 'r>(
+                long: &'r Varying<'l, 'lower, Upper, Self>,
+            ) -> &'r Varying<'s, 'lower, Upper, Self>
+            where
+                Upper: 'l,
+                'l: 's,
+                's: 'lower,
+                Varying<'l, 'lower, Upper, Self>: 'r,
+                Varying<'s, 'lower, Upper, Self>: 'r,
+            {
+                #![expect(
+                    clippy::unnecessary_safety_comment,
+                    reason = "implementation safety of method",
+                )]
+                // Implementation safety: this is a covariant cast with some assertions.
+                // There are no possible sources of panics other than the
+                // `Self::covariant_assertions()` call.
+                Self::covariant_assertions();
+
+                let src: &'r ($(Varying<'l, 'lower, Upper, $Ti>,)+) = long;
+                // SAFETY: we are shortening the `'l` lifetime of each `Ti<'l>` to `'s`, which is
+                // at least as long as `'lower`. We called each `Ti::covariant_assertions()`
+                // (within `Self::covariant_assertions()`), so covariantly casting each
+                // `Ti<'varying>` is sound.
+                let dst: &'r ($(Varying<'s, 'lower, Upper, $Ti>,)+) = unsafe { transmute(src) };
+                dst
+            }
+        }
+
+        // SAFETY:
+        // - If `Self::contravariant_assertions()` does not panic,
+        //   then `Self<'varying>` is contravariant over `'varying`.
+        //
+        //   The former implies that each `Ti::contravariant_assertions()` does not panic,
+        //   in which case each `Ti<'varying>` is contravariant over `'varying`,
+        //   implying that `(.., Ti<'varying>, ..)` is contravariant over `'varying`.
+        //
+        // - No assertions are included other than those in `Self::contravariant_assertions()`.
+        // - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+        unsafe impl<'lower, Upper, $($Ti),+> ContravariantFamily<'lower, Upper> for ($($Ti,)+)
+        where
+            Upper: ?Sized,
+            $(
+                $Ti: ContravariantFamily<'lower, Upper>,
+                for<'varying> Varying<'varying, 'lower, Upper, $Ti>: Sized,
+            )+
+        {
+            #[inline]
+            fn contravariant_assertions() {
+                $(
+                    $Ti::contravariant_assertions();
+                )+
+            }
+
+            #[inline]
+            fn lengthen<'s, 'l>(
+                short: Varying<'s, 'lower, Upper, Self>,
+            ) -> Varying<'l, 'lower, Upper, Self>
+            where
+                Upper: 'l,
+                'l: 's,
+                's: 'lower,
+                for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+            {
+                #![expect(
+                    clippy::unnecessary_safety_comment,
+                    reason = "implementation safety of method",
+                )]
+                // Implementation safety: this is just a contravariant cast of each element with,
+                // possibly, some assertions. Any possible sources of panics in each
+                // `Ti::lengthen` must be included in `Ti::contravariant_assertions`, which are
+                // included in `Self::contravariant_assertions`.
+
+                let ($($ti,)+) = short;
+                ($($Ti::lengthen($ti),)+)
+            }
+
+            #[inline]
+            fn lengthen_ref<'s, 'l, 'r>(
+                short: &'r Varying<'s, 'lower, Upper, Self>,
+            ) -> &'r Varying<'l, 'lower, Upper, Self>
+            where
+                Upper: 'l,
+                'l: 's,
+                's: 'lower,
+                Varying<'l, 'lower, Upper, Self>: 'r,
+                Varying<'s, 'lower, Upper, Self>: 'r,
+            {
+                #![expect(
+                    clippy::unnecessary_safety_comment,
+                    reason = "implementation safety of method",
+                )]
+                // Implementation safety: this is a contravariant cast with some assertions.
+                // There are no possible sources of panics other than the
+                // `Self::contravariant_assertions()` call.
+                Self::contravariant_assertions();
+
+                let src: &'r ($(Varying<'s, 'lower, Upper, $Ti>,)+) = short;
+                // SAFETY: we are lengthening the `'s` lifetime of each `Ti<'s>` to `'l`, which is
+                // at most as long as any lifetime in `Upper`. We called each
+                // `Ti::contravariant_assertions()` (within `Self::contravariant_assertions()`),
+                // so contravariantly casting each `Ti<'varying>` is sound.
+                let dst: &'r ($(Varying<'l, 'lower, Upper, $Ti>,)+) = unsafe { transmute(src) };
+                dst
+            }
+        }
+    };
+}
+
+tuple_family!(T1 t1);
+tuple_family!(T1 t1, T2 t2);
+tuple_family!(T1 t1, T2 t2, T3 t3);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10);
+tuple_family!(T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11);
+tuple_family!(
+    T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11, T12 t12
+);
+tuple_family!(
+    T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11, T12 t12,
+    T13 t13
+);
+tuple_family!(
+    T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11, T12 t12,
+    T13 t13, T14 t14
+);
+tuple_family!(
+    T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11, T12 t12,
+    T13 t13, T14 t14, T15 t15
+);
+tuple_family!(
+    T1 t1, T2 t2, T3 t3, T4 t4, T5 t5, T6 t6, T7 t7, T8 t8, T9 t9, T10 t10, T11 t11, T12 t12,
+    T13 t13, T14 t14, T15 t15, T16 t16
+);
+
+
+// ================================================================
+//  option::Option<T>
+// ================================================================
+
+// Safety summary:
+// - `Option<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Option<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+/// An optional value of the `T<'varying>` family, such as an optional reference.
+///
+/// # Example
+/// ```
+/// use variance_family::{CovariantFamily, Varying, VaryingRef};
+///
+/// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), Option<VaryingRef<str>>>) -> Option<&'b str> {
+///     <Option<VaryingRef<str>> as CovariantFamily<'b, ()>>::shorten(long)
+/// }
+///
+/// assert_eq!(shorten(Some("hello")), Some("hello"));
+/// assert_eq!(shorten(None), None);
+/// ```
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Option<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Option<T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Option<T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Option<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Option<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Option<T<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Option<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Option<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Option<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Option<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Option<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Option<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Option<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Option<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Option<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Option<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  Pin<P>
+// ================================================================
+
+// Safety summary:
+// - `Pin<P<'varying>>` is covariant over `'varying` if `P<'varying>` is covariant over
+//   `'varying`.
+// - `Pin<P<'varying>>` is contravariant over `'varying` if `P<'varying>` is contravariant
+//   over it.
+//
+// `Pin<P<'varying>>` is a `repr(transparent)` wrapper around `P<'varying>`, and changing the
+// lifetime of the pointer does not move the pointee, so the pinning guarantees are unaffected.
+
+impl<'varying, 'lower, Upper, P> WithLifetime<'varying, 'lower, Upper> for Pin<P>
+where
+    Upper: ?Sized,
+    P: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Pin<P::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `P::covariant_assertions()` does not panic,
+//   in which case `P<'varying>` is covariant over `'varying`,
+//   implying that `Pin<P<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, P> CovariantFamily<'lower, Upper> for Pin<P>
+where
+    Upper: ?Sized,
+    P: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, P>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        P::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Pin<Varying<'l, 'lower, Upper, P>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `P<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `P::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `P<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `Pin<P<'s>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: Pin<Varying<'s, 'lower, Upper, P>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Pin<Varying<'l, 'lower, Upper, P>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `P<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `P::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `P<'varying>` is sound.
+        let dst: &'r Pin<Varying<'s, 'lower, Upper, P>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `P::contravariant_assertions()` does not panic,
+//   in which case `P<'varying>` is contravariant over `'varying`,
+//   implying that `Pin<P<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, P> ContravariantFamily<'lower, Upper> for Pin<P>
+where
+    Upper: ?Sized,
+    P: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, P>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        P::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::contravariant_assertions()`
+        // call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Pin<Varying<'s, 'lower, Upper, P>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `P<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `P::contravariant_assertions()`
+        // (within `Self::contravariant_assertions()`), so contravariantly casting `P<'varying>`
+        // is sound. Changing lifetimes does not change the layout of a type, so reading the
+        // `Pin<P<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: Pin<Varying<'l, 'lower, Upper, P>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::contravariant_assertions()`
+        // call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Pin<Varying<'s, 'lower, Upper, P>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `P<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `P::contravariant_assertions()`
+        // (within `Self::contravariant_assertions()`), so contravariantly casting `P<'varying>`
+        // is sound.
+        let dst: &'r Pin<Varying<'l, 'lower, Upper, P>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+
+// ================================================================
+//  iter::Enumerate<I>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Enumerate<I<'varying>>` is covariant over `'varying` if `I<'varying>` is covariant over
+//   `'varying`.
+// - `Enumerate<I<'varying>>` is contravariant over `'varying` if `I<'varying>` is contravariant
+//   over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, I> WithLifetime<'varying, 'lower, Upper> for Enumerate<I>
+where
+    Upper: ?Sized,
+    I: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Enumerate<I::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `I::covariant_assertions()` does not panic, in which case `I<'varying>`
+//   is covariant over `'varying`, implying that `Enumerate<I<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, I> CovariantFamily<'lower, Upper> for Enumerate<I>
+where
+    Upper: ?Sized,
+    I: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        I::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Enumerate<Varying<'l, 'lower, Upper, I>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `I::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `I<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Enumerate<I<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Enumerate<Varying<'s, 'lower, Upper, I>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Enumerate<Varying<'l, 'lower, Upper, I>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `I::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `I<'varying>` is sound.
+        let dst: &'r Enumerate<Varying<'s, 'lower, Upper, I>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `I::contravariant_assertions()` does not panic, in which case
+//   `I<'varying>` is contravariant over `'varying`, implying that `Enumerate<I<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, I> ContravariantFamily<'lower, Upper> for Enumerate<I>
+where
+    Upper: ?Sized,
+    I: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        I::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Enumerate<Varying<'s, 'lower, Upper, I>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `I::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `I<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `Enumerate<I<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: Enumerate<Varying<'l, 'lower, Upper, I>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Enumerate<Varying<'s, 'lower, Upper, I>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `I::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `I<'varying>` is sound.
+        let dst: &'r Enumerate<Varying<'l, 'lower, Upper, I>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  iter::Chain<A, B>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Chain<A<'varying>, B<'varying>>` is covariant over `'varying` if `A<'varying>` and
+//   `B<'varying>` are covariant over `'varying`.
+// - `Chain<A<'varying>, B<'varying>>` is contravariant over `'varying` if `A<'varying>` and
+//   `B<'varying>` are contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, A, B> WithLifetime<'varying, 'lower, Upper> for Chain<A, B>
+where
+    Upper: ?Sized,
+    A: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    B: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Chain<A::Is, B::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `A::covariant_assertions()` and `B::covariant_assertions()` do not
+//   panic, in which case `A<'varying>` and `B<'varying>` are covariant over `'varying`, implying
+//   that `Chain<A<'varying>, B<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, A, B> CovariantFamily<'lower, Upper> for Chain<A, B>
+where
+    Upper: ?Sized,
+    A: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, A>: Sized,
+    B: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, B>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        A::covariant_assertions();
+        B::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `A<'l>` and `B<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `A::covariant_assertions()` and
+        // `B::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `A<'varying>` and `B<'varying>` is sound. Changing lifetimes does not change the
+        // layout of a type, so reading the `Chain<A<'s>, B<'s>>` out of the `ManuallyDrop` is
+        // sound, and the source value is never dropped.
+        let dst: Chain<
+            Varying<'s, 'lower, Upper, A>,
+            Varying<'s, 'lower, Upper, B>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Chain<Varying<'l, 'lower, Upper, A>, Varying<'l, 'lower, Upper, B>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `A<'l>` and `B<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `A::covariant_assertions()` and
+        // `B::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `A<'varying>` and `B<'varying>` is sound.
+        let dst: &'r Chain<
+            Varying<'s, 'lower, Upper, A>,
+            Varying<'s, 'lower, Upper, B>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `A::contravariant_assertions()` and `B::contravariant_assertions()` do
+//   not panic, in which case `A<'varying>` and `B<'varying>` are contravariant over `'varying`,
+//   implying that `Chain<A<'varying>, B<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, A, B> ContravariantFamily<'lower, Upper> for Chain<A, B>
+where
+    Upper: ?Sized,
+    A: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, A>: Sized,
+    B: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, B>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        A::contravariant_assertions();
+        B::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `A<'s>` and `B<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `A::contravariant_assertions()` and
+        // `B::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `A<'varying>` and `B<'varying>` is sound. Changing lifetimes does
+        // not change the layout of a type, so reading the `Chain<A<'l>, B<'l>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Chain<
+            Varying<'l, 'lower, Upper, A>,
+            Varying<'l, 'lower, Upper, B>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Chain<Varying<'s, 'lower, Upper, A>, Varying<'s, 'lower, Upper, B>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `A<'s>` and `B<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `A::contravariant_assertions()` and
+        // `B::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `A<'varying>` and `B<'varying>` is sound.
+        let dst: &'r Chain<
+            Varying<'l, 'lower, Upper, A>,
+            Varying<'l, 'lower, Upper, B>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  iter::Peekable<I>    (as `PeekableFamily<I, Item>`, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Peekable<I<'varying>>` is covariant over `'varying` if `I<'varying>` and `Item<'varying>` are
+//   covariant over `'varying`.
+// - `Peekable<I<'varying>>` is contravariant over `'varying` if `I<'varying>` and `Item<'varying>`
+//   are contravariant over `'varying`.
+//
+// The `Item<'varying>` family is needed because `Peekable<I<'varying>>` may store a peeked
+// `<I<'varying> as Iterator>::Item` value, whose variance is not implied by that of
+// `I<'varying>`. For instance, an iterator could be covariant over `'varying` while yielding
+// `&'varying mut &'varying u8` items.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `Peekable<I<'varying>>` lifetime family, where `I<'varying>` is an iterator over
+    /// `Item<'varying>`.
+    ///
+    /// If `I<'varying>` and `Item<'varying>` are covariant over `'varying`, then
+    /// `Peekable<I<'varying>>` is covariant over `'varying`.
+    ///
+    /// If `I<'varying>` and `Item<'varying>` are contravariant over `'varying`, then
+    /// `Peekable<I<'varying>>` is contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct PeekableFamily<I, Item>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, I, Item> WithLifetime<'varying, 'lower, Upper>
+for PeekableFamily<I, Item>
+where
+    Upper: ?Sized,
+    I: WithLifetime<'varying, 'lower, Upper, Is: Sized + Iterator<Item = Item::Is>>,
+    Item: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Peekable<I::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `I::covariant_assertions()` and `Item::covariant_assertions()` do not
+//   panic, in which case `I<'varying>` and `Item<'varying>` are covariant over `'varying`, implying
+//   that `Peekable<I<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, I, Item> CovariantFamily<'lower, Upper> for PeekableFamily<I, Item>
+where
+    Upper: ?Sized,
+    I: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+    Item: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, Item>: Sized,
+    Self: LifetimeFamily<'lower, Upper>,
+{
+    #[inline]
+    fn covariant_assertions() {
+        I::covariant_assertions();
+        Item::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` and `Item<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `I::covariant_assertions()` and
+        // `Item::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `I<'varying>` and `Item<'varying>` is sound. Changing lifetimes does not change
+        // the layout of a type, so reading the `Peekable<I<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Varying<'s, 'lower, Upper, Self> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Varying<'l, 'lower, Upper, Self> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` and `Item<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `I::covariant_assertions()` and
+        // `Item::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `I<'varying>` and `Item<'varying>` is sound.
+        let dst: &'r Varying<'s, 'lower, Upper, Self> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `I::contravariant_assertions()` and `Item::contravariant_assertions()`
+//   do not panic, in which case `I<'varying>` and `Item<'varying>` are contravariant over
+//   `'varying`, implying that `Peekable<I<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, I, Item> ContravariantFamily<'lower, Upper>
+for PeekableFamily<I, Item>
+where
+    Upper: ?Sized,
+    I: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+    Item: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, Item>: Sized,
+    Self: LifetimeFamily<'lower, Upper>,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        I::contravariant_assertions();
+        Item::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` and `Item<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `I::contravariant_assertions()` and
+        // `Item::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `I<'varying>` and `Item<'varying>` is sound. Changing lifetimes
+        // does not change the layout of a type, so reading the `Peekable<I<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Varying<'l, 'lower, Upper, Self> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Varying<'s, 'lower, Upper, Self> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` and `Item<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `I::contravariant_assertions()` and
+        // `Item::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `I<'varying>` and `Item<'varying>` is sound.
+        let dst: &'r Varying<'l, 'lower, Upper, Self> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  iter::Zip<A, B>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Zip<A<'varying>, B<'varying>>` is covariant over `'varying` if `A<'varying>` and `B<'varying>`
+//   are covariant over `'varying`.
+// - `Zip<A<'varying>, B<'varying>>` is contravariant over `'varying` if `A<'varying>` and
+//   `B<'varying>` are contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, A, B> WithLifetime<'varying, 'lower, Upper> for Zip<A, B>
+where
+    Upper: ?Sized,
+    A: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    B: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Zip<A::Is, B::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `A::covariant_assertions()` and `B::covariant_assertions()` do not
+//   panic, in which case `A<'varying>` and `B<'varying>` are covariant over `'varying`, implying
+//   that `Zip<A<'varying>, B<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, A, B> CovariantFamily<'lower, Upper> for Zip<A, B>
+where
+    Upper: ?Sized,
+    A: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, A>: Sized,
+    B: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, B>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        A::covariant_assertions();
+        B::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `A<'l>` and `B<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `A::covariant_assertions()` and
+        // `B::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `A<'varying>` and `B<'varying>` is sound. Changing lifetimes does not change the
+        // layout of a type, so reading the `Zip<A<'s>, B<'s>>` out of the `ManuallyDrop` is sound,
+        // and the source value is never dropped.
+        let dst: Zip<
+            Varying<'s, 'lower, Upper, A>,
+            Varying<'s, 'lower, Upper, B>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Zip<Varying<'l, 'lower, Upper, A>, Varying<'l, 'lower, Upper, B>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `A<'l>` and `B<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `A::covariant_assertions()` and
+        // `B::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `A<'varying>` and `B<'varying>` is sound.
+        let dst: &'r Zip<
+            Varying<'s, 'lower, Upper, A>,
+            Varying<'s, 'lower, Upper, B>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `A::contravariant_assertions()` and `B::contravariant_assertions()` do
+//   not panic, in which case `A<'varying>` and `B<'varying>` are contravariant over `'varying`,
+//   implying that `Zip<A<'varying>, B<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, A, B> ContravariantFamily<'lower, Upper> for Zip<A, B>
+where
+    Upper: ?Sized,
+    A: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, A>: Sized,
+    B: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, B>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        A::contravariant_assertions();
+        B::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `A<'s>` and `B<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `A::contravariant_assertions()` and
+        // `B::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `A<'varying>` and `B<'varying>` is sound. Changing lifetimes does
+        // not change the layout of a type, so reading the `Zip<A<'l>, B<'l>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Zip<
+            Varying<'l, 'lower, Upper, A>,
+            Varying<'l, 'lower, Upper, B>,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Zip<Varying<'s, 'lower, Upper, A>, Varying<'s, 'lower, Upper, B>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `A<'s>` and `B<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `A::contravariant_assertions()` and
+        // `B::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `A<'varying>` and `B<'varying>` is sound.
+        let dst: &'r Zip<
+            Varying<'l, 'lower, Upper, A>,
+            Varying<'l, 'lower, Upper, B>,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  iter::Flatten<I>    (as `FlattenFamily<I, Inner>`, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Flatten<I<'varying>>` is covariant over `'varying` if `I<'varying>` and `Inner<'varying>` are
+//   covariant over `'varying`.
+// - `Flatten<I<'varying>>` is contravariant over `'varying` if `I<'varying>` and `Inner<'varying>`
+//   are contravariant over `'varying`.
+//
+// The `Inner<'varying>` family, of the iterators obtained from the items of `I<'varying>`, is
+// needed because `Flatten<I<'varying>>` may store a partially-consumed front and back inner
+// iterator, whose variance is not implied by that of `I<'varying>`.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `Flatten<I<'varying>>` lifetime family, where `I<'varying>` is an iterator whose items
+    /// can be converted into `Inner<'varying>` iterators.
+    ///
+    /// If `I<'varying>` and `Inner<'varying>` are covariant over `'varying`, then
+    /// `Flatten<I<'varying>>` is covariant over `'varying`.
+    ///
+    /// If `I<'varying>` and `Inner<'varying>` are contravariant over `'varying`, then
+    /// `Flatten<I<'varying>>` is contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct FlattenFamily<I, Inner>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, I, Inner> WithLifetime<'varying, 'lower, Upper>
+for FlattenFamily<I, Inner>
+where
+    Upper: ?Sized,
+    I: WithLifetime<'varying, 'lower, Upper, Is: Sized + Iterator>,
+    <I::Is as Iterator>::Item: IntoIterator<IntoIter = Inner::Is>,
+    Inner: WithLifetime<'varying, 'lower, Upper, Is: Sized + Iterator>,
+{
+    type Is = Flatten<I::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `I::covariant_assertions()` and `Inner::covariant_assertions()` do
+//   not panic, in which case `I<'varying>` and `Inner<'varying>` are covariant over `'varying`,
+//   implying that `Flatten<I<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, I, Inner> CovariantFamily<'lower, Upper> for FlattenFamily<I, Inner>
+where
+    Upper: ?Sized,
+    I: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+    Inner: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, Inner>: Sized,
+    Self: LifetimeFamily<'lower, Upper>,
+{
+    #[inline]
+    fn covariant_assertions() {
+        I::covariant_assertions();
+        Inner::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` and `Inner<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `I::covariant_assertions()` and
+        // `Inner::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `I<'varying>` and `Inner<'varying>` is sound. Changing lifetimes does not change
+        // the layout of a type, so reading the `Flatten<I<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Varying<'s, 'lower, Upper, Self> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Varying<'l, 'lower, Upper, Self> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `I<'l>` and `Inner<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `I::covariant_assertions()` and
+        // `Inner::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `I<'varying>` and `Inner<'varying>` is sound.
+        let dst: &'r Varying<'s, 'lower, Upper, Self> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `I::contravariant_assertions()` and `Inner::contravariant_assertions()`
+//   do not panic, in which case `I<'varying>` and `Inner<'varying>` are contravariant over
+//   `'varying`, implying that `Flatten<I<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, I, Inner> ContravariantFamily<'lower, Upper>
+for FlattenFamily<I, Inner>
+where
+    Upper: ?Sized,
+    I: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, I>: Sized,
+    Inner: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, Inner>: Sized,
+    Self: LifetimeFamily<'lower, Upper>,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        I::contravariant_assertions();
+        Inner::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` and `Inner<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `I::contravariant_assertions()` and
+        // `Inner::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `I<'varying>` and `Inner<'varying>` is sound. Changing lifetimes
+        // does not change the layout of a type, so reading the `Flatten<I<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Varying<'l, 'lower, Upper, Self> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Varying<'s, 'lower, Upper, Self> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `I<'s>` and `Inner<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `I::contravariant_assertions()` and
+        // `Inner::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `I<'varying>` and `Inner<'varying>` is sound.
+        let dst: &'r Varying<'l, 'lower, Upper, Self> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  fmt::Arguments<'varying>    (VaryingArguments, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `fmt::Arguments<'varying>` is covariant over `'varying`, and the compiler knows it.
+//   Unsafe transmutes aren't even needed.
+// - `fmt::Arguments<'varying>` is never contravariant over `'varying`.
+
+/// The `fmt::Arguments<'varying>` lifetime family.
+///
+/// This lifetime family is covariant over `'varying`, and is never contravariant over
+/// `'varying`.
+///
+/// Note that this type itself is just a marker ZST for the family.
+#[cfg(feature = "more_impls")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VaryingArguments;
+
+#[cfg(feature = "more_impls")]
+impl<'varying, Upper: ?Sized> WithLifetime<'varying, '_, Upper> for VaryingArguments {
+    type Is = Arguments<'varying>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `fmt::Arguments<'varying>`
+//   is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for VaryingArguments {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `fmt::Arguments<'varying>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  slice::Iter<'varying, T>    (VaryingSliceIter<T>, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `slice::Iter<'varying, T<'varying>>` is covariant over `'varying` if `T<'varying>` is
+//   covariant over it.
+// - `slice::Iter<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `slice::Iter<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` is covariant over `'varying`, then `slice::Iter<'varying, T<'varying>>`
+    /// is covariant over `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingSliceIter<T>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingSliceIter<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    T::Is: 'varying,
+{
+    type Is = SliceIter<'varying, T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic,
+//   in which case `T<'varying>` is covariant over `'varying`,
+//   implying that `slice::Iter<'varying, T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingSliceIter<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized + 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<SliceIter<'l, Varying<'l, 'lower, Upper, T>>>
+            = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`, and `slice::Iter<'l, _>` is covariant over `'l`. We called
+        // `T::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `T<'varying>` is sound. Changing lifetimes does not change the layout of a type,
+        // so reading the `slice::Iter<'s, T<'s>>` out of the `ManuallyDrop` is sound, and the
+        // source value is never dropped.
+        let dst: SliceIter<'s, Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r SliceIter<'l, Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`, and `slice::Iter<'l, _>` is covariant over `'l`. We called
+        // `T::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `T<'varying>` is sound.
+        let dst: &'r SliceIter<'s, Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+// `slice::Iter<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  slice::IterMut<'varying, T>    (VaryingSliceIterMut<T>, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `slice::IterMut<'varying, U>` is covariant over `'varying`. Below, `T<'varying>` families are
+//   used which implement `UnvaryingFamily`, making them equivalent to `slice::IterMut<'varying, U>`
+//   for some type `U`. Unsafe transmutes aren't even needed.
+// - `slice::IterMut<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `slice::IterMut<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` does not actually use `'varying` at all (making it some fixed type `U`
+    /// regardless of `'varying`), then `slice::IterMut<'varying, T<'varying>>` is covariant over
+    /// `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingSliceIterMut<T>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingSliceIterMut<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    T::Is: 'varying,
+{
+    type Is = SliceIterMut<'varying, T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `T<'varying>` does not
+//   actually use `'varying` at all, so `slice::IterMut<'varying, T<'varying>>` can be treated as
+//   `slice::IterMut<'varying, U>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingSliceIterMut<T>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `slice::IterMut<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
+
+
+// ================================================================
+//  cell::Ref<'varying, T>    (VaryingCellRef<T>)
+// ================================================================
+
+// Safety summary:
+// - `cell::Ref<'varying, T<'varying>>` is covariant over `'varying` if `T<'varying>` is
+//   covariant over it.
+// - `cell::Ref<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+invariant_zst!(
+    /// The `cell::Ref<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` is covariant over `'varying`, then `cell::Ref<'varying, T<'varying>>`
+    /// is covariant over `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    ///
+    /// # Example
+    /// ```
+    /// use core::cell::{Ref, RefCell};
+    /// use variance_family::{CovariantFamily, Varying, VaryingRef};
+    /// use variance_family::cell::VaryingCellRef;
+    ///
+    /// type RefOfStr = VaryingCellRef<VaryingRef<str>>;
+    ///
+    /// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), RefOfStr>) -> Ref<'b, &'b str> {
+    ///     <RefOfStr as CovariantFamily<'b, ()>>::shorten(long)
+    /// }
+    ///
+    /// let cell = RefCell::new("hello");
+    /// assert_eq!(*shorten(cell.borrow()), "hello");
+    /// ```
+    pub struct VaryingCellRef<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingCellRef<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'varying,
+{
+    type Is = Ref<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic,
+//   in which case `T<'varying>` is covariant over `'varying`,
+//   implying that `cell::Ref<'varying, T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingCellRef<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Ref<'l, Varying<'l, 'lower, Upper, T>>>
+            = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`, and `cell::Ref<'l, _>` is covariant over `'l`. We called
+        // `T::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `T<'varying>` is sound. Changing lifetimes does not change the layout of a type,
+        // so reading the `cell::Ref<'s, T<'s>>` out of the `ManuallyDrop` is sound, and the
+        // source value is never dropped.
+        let dst: Ref<'s, Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Ref<'l, Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`, and `cell::Ref<'l, _>` is covariant over `'l`. We called
+        // `T::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `T<'varying>` is sound.
+        let dst: &'r Ref<'s, Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+// `cell::Ref<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  cell::RefMut<'varying, T>    (VaryingCellRefMut<T>)
+// ================================================================
+
+// Safety summary:
+// - `cell::RefMut<'varying, U>` is covariant over `'varying`. Below, `T<'varying>` families are
+//   used which implement `UnvaryingFamily`, making them equivalent to `cell::RefMut<'varying, U>`
+//   for some type `U`. Unsafe transmutes aren't even needed.
+// - `cell::RefMut<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+invariant_zst!(
+    /// The `cell::RefMut<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` does not actually use `'varying` at all (making it some fixed type `U`
+    /// regardless of `'varying`), then `cell::RefMut<'varying, T<'varying>>` is covariant over
+    /// `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    ///
+    /// # Example
+    /// ```
+    /// use core::cell::{RefCell, RefMut};
+    /// use variance_family::{CovariantFamily, Unvarying, Varying};
+    /// use variance_family::cell::VaryingCellRefMut;
+    ///
+    /// type RefMutOfString = VaryingCellRefMut<Unvarying<String>>;
+    ///
+    /// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), RefMutOfString>) -> RefMut<'b, String> {
+    ///     <RefMutOfString as CovariantFamily<'b, ()>>::shorten(long)
+    /// }
+    ///
+    /// let cell = RefCell::new(String::from("hello"));
+    /// shorten(cell.borrow_mut()).push_str(" world");
+    /// assert_eq!(*cell.borrow(), "hello world");
+    /// ```
+    pub struct VaryingCellRefMut<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingCellRefMut<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'varying,
+{
+    type Is = RefMut<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `T<'varying>` does not
+//   actually use `'varying` at all, so `cell::RefMut<'varying, T<'varying>>` can be treated as
+//   `cell::RefMut<'varying, U>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingCellRefMut<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `cell::RefMut<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
+
+
+// ================================================================
+//  cell::OnceCell<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `OnceCell<U>` is bivariant over `'varying` (as it's entirely unused). Below, `T<'varying>`
+//   families are used which implement `UnvaryingFamily`, making them equivalent to `OnceCell<U>`
+//   for some type `U`. Unsafe transmutes aren't even needed.
+// - Like `Cell<T<'varying>>`, a shared `&OnceCell<T<'varying>>` can be used to set the value, so
+//   `OnceCell<T<'varying>>` is not covariant or contravariant over `'varying` in general.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for OnceCell<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = OnceCell<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for OnceCell<T>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for OnceCell<T>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  cell::LazyCell<T, F>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `LazyCell<U, F>` is bivariant over `'varying` (as it's entirely unused). Below, `T<'varying>`
+//   families are used which implement `UnvaryingFamily`, making them equivalent to
+//   `LazyCell<U, F>` for some type `U`. Unsafe transmutes aren't even needed.
+// - The value of a `LazyCell<T<'varying>, F>` is initialized through a shared reference, and `F`
+//   is a fixed type which must produce a `T<'varying>`, so `LazyCell<T<'varying>, F>` is not
+//   covariant or contravariant over `'varying` in general.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T, F> WithLifetime<'varying, 'lower, Upper> for LazyCell<T, F>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = LazyCell<T::Is, F>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T, F> CovariantFamily<'lower, Upper> for LazyCell<T, F>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T, F> ContravariantFamily<'lower, Upper> for LazyCell<T, F>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  marker::PhantomData<T>
+// ================================================================
+
+// Safety summary:
+// - `PhantomData<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `PhantomData<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+//
+// A `PhantomData<T<'varying>>` holds no `T<'varying>` value, so casts are trivially memory-safe,
+// and no transmutes are needed. However, code using `PhantomData` as a marker may rely on its
+// compiler-assigned variance, so the variance of `T<'varying>` is still required.
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for PhantomData<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+{
+    type Is = PhantomData<T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `PhantomData<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for PhantomData<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + CovariantFamily<'lower, Upper>,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let PhantomData = long;
+        PhantomData
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let _: &'r Varying<'l, 'lower, Upper, Self> = long;
+        &PhantomData
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `PhantomData<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for PhantomData<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + ContravariantFamily<'lower, Upper>,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let PhantomData = short;
+        PhantomData
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let _: &'r Varying<'s, 'lower, Upper, Self> = short;
+        &PhantomData
+    }
+}
+
+
+// ================================================================
+//  mem::MaybeUninit<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `MaybeUninit<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `MaybeUninit<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+//
+// Changing lifetimes does not change the layout or validity requirements of a type, and a
+// `MaybeUninit<T<'varying>>` which is not initialized holds no `T<'varying>` value whose variance
+// could matter, so the variance of `T<'varying>` alone determines that of `MaybeUninit`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for MaybeUninit<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = MaybeUninit<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `MaybeUninit<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for MaybeUninit<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<MaybeUninit<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `MaybeUninit<T<'s>>` out
+        // of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: MaybeUninit<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r MaybeUninit<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r MaybeUninit<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `MaybeUninit<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for MaybeUninit<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `MaybeUninit<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: MaybeUninit<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r MaybeUninit<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r MaybeUninit<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  num::NonZero<T>    (for each integer `T`, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - Each `NonZero<T>` (for an integer type `T`) is bivariant over `'varying` (as it's entirely
+//   unused).
+//
+// `NonZero<T>` is only generic over the sealed `ZeroablePrimitive` trait, which cannot be named on
+// stable Rust, so each integer type is implemented separately. The `NonZeroU8` (and similar) type
+// aliases refer to the same types.
+
+#[cfg(feature = "more_impls")]
+lifetimeless_family!(
+    NonZero<u8>, NonZero<u16>, NonZero<u32>, NonZero<u64>, NonZero<u128>, NonZero<usize>,
+    NonZero<i8>, NonZero<i16>, NonZero<i32>, NonZero<i64>, NonZero<i128>, NonZero<isize>,
+);
+
+
+// ================================================================
+//  num::Saturating<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Saturating<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Saturating<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Saturating<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Saturating<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Saturating<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Saturating<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Saturating<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Saturating<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Saturating<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Saturating<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Saturating<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Saturating<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Saturating<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Saturating<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `Saturating<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: Saturating<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Saturating<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Saturating<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  num::Wrapping<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Wrapping<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Wrapping<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Wrapping<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Wrapping<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Wrapping<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Wrapping<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Wrapping<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Wrapping<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Wrapping<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Wrapping<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Wrapping<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Wrapping<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Wrapping<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Wrapping<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Wrapping<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Wrapping<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Wrapping<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Wrapping<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  cmp::Reverse<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Reverse<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Reverse<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Reverse<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Reverse<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Reverse<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Reverse<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Reverse<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Reverse<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Reverse<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Reverse<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Reverse<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Reverse<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Reverse<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Reverse<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Reverse<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Reverse<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Reverse<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Reverse<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::Bound<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Bound<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Bound<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Bound<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Bound<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Bound<T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Bound<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Bound<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Bound<T<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Bound<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Bound<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Bound<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Bound<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Bound<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Bound<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Bound<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Bound<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Bound<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Bound<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::RangeFull    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `RangeFull` is bivariant over `'varying` (as it's entirely unused).
+//
+// Note that the range types cannot be covered by a single blanket impl over `RangeBounds<T>`
+// implementors, since it would overlap with every other impl of these traits; each range type is
+// implemented separately below.
+
+#[cfg(feature = "more_impls")]
+lifetimeless_family!(RangeFull);
+
+
+// ================================================================
+//  ops::Range<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `Range<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Range<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Range<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Range<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Range<T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Range<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Range<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Range<T<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Range<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Range<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Range<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Range<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Range<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Range<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Range<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Range<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Range<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Range<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::RangeFrom<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `RangeFrom<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `RangeFrom<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for RangeFrom<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = RangeFrom<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `RangeFrom<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for RangeFrom<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<RangeFrom<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `RangeFrom<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: RangeFrom<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RangeFrom<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeFrom<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `RangeFrom<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for RangeFrom<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<RangeFrom<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `RangeFrom<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: RangeFrom<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r RangeFrom<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeFrom<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::RangeTo<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `RangeTo<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `RangeTo<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for RangeTo<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = RangeTo<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `RangeTo<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for RangeTo<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<RangeTo<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `RangeTo<T<'s>>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: RangeTo<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RangeTo<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeTo<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `RangeTo<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for RangeTo<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<RangeTo<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `RangeTo<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: RangeTo<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r RangeTo<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeTo<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::RangeInclusive<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `RangeInclusive<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `RangeInclusive<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is
+//   contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for RangeInclusive<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = RangeInclusive<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `RangeInclusive<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for RangeInclusive<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `RangeInclusive<T<'s>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: RangeInclusive<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RangeInclusive<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeInclusive<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `RangeInclusive<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for RangeInclusive<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `RangeInclusive<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is never
+        // dropped.
+        let dst: RangeInclusive<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r RangeInclusive<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeInclusive<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  ops::RangeToInclusive<T>    (with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `RangeToInclusive<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `RangeToInclusive<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is
+//   contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for RangeToInclusive<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = RangeToInclusive<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `RangeToInclusive<T<'varying>>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for RangeToInclusive<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `RangeToInclusive<T<'s>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: RangeToInclusive<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RangeToInclusive<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeToInclusive<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `RangeToInclusive<T<'varying>>`
+//   is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for RangeToInclusive<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `RangeToInclusive<T<'l>>` out of the `ManuallyDrop` is sound, and the source value is
+        // never dropped.
+        let dst: RangeToInclusive<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r RangeToInclusive<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r RangeToInclusive<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
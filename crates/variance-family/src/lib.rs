@@ -28,9 +28,9 @@ mod traits;
 /// An `Unvarying` type that implements `UnvaryingFamily`, greatly useful for trivial families not
 /// implemented here.
 mod unvarying;
-/// `covariant`, `contravariant`, and `unvarying` macros that cover common cases, in addition to
-/// `recursive_covariant`, `recursive_contravariant`, `recursive_unvarying`, and
-/// `recursive_covariant_for_unvarying` macros that require some `unsafe` to use.
+/// `covariant`, `contravariant`, `bivariant`, and `unvarying` macros that cover common cases, in
+/// addition to `recursive_covariant` and `recursive_contravariant` macros that require some
+/// `unsafe` to use.
 ///
 /// Additionally, an `invariant_zst` macro mainly used for their backend is included.
 mod macros;
@@ -41,6 +41,31 @@ mod macros;
 // In the event that a new lifetime family *is* needed, then hopefully the `macros` module
 // makes it easier.
 
+/// Compile-time assertions that a family produces some type, or has some variance.
+///
+/// # Example
+/// ```
+/// use variance_family::assertions::{assert_contravariant, assert_covariant, assert_family_is};
+///
+/// // Every primitive type is its own bivariant lifetime family.
+/// macro_rules! assert_bivariant {
+///     ($($ty:ty),+ $(,)?) => {$(
+///         const {
+///             assert_family_is::<'static, 'static, (), $ty, $ty>();
+///             assert_covariant::<'static, (), $ty>();
+///             assert_contravariant::<'static, (), $ty>();
+///         }
+///     )+};
+/// }
+///
+/// assert_bivariant!(
+///     bool, char, f32, f64,
+///     i8, i16, i32, i64, i128, isize,
+///     u8, u16, u32, u64, u128, usize,
+/// );
+/// ```
+pub mod assertions;
+
 /// Implementations for `&'a T`, `&'varying T` (as `VaryingRef<T>`), and `*const T`.
 mod main_const_impls;
 /// Implementations for `&'a mut T`, `&'varying mut T` (as `VaryingMut<T>`), and `*mut T`.
@@ -49,28 +74,44 @@ mod main_mut_impls;
 mod main_fn_impls;
 
 /// Implementations for:
-/// `[T]`, `[T; N]`, `(T1, ..., Tn)`, `bool`, `char`, floats, ints, uints, `str`,
-/// `cell::{Cell, Ref, RefCell, RefMut}`, `option::Option`, `pin::Pin`, `result::Result`,
+/// `[T]`, `[T; N]`, `(T1, ..., Tn)` (for `n` up to 16), `bool`, `char`, floats, ints, uints,
+/// `str`, `cell::{Cell, Ref, RefCell, RefMut}`, `cell::Ref<'varying, T>` (as `VaryingCellRef<T>`),
+/// `cell::RefMut<'varying, T>` (as `VaryingCellRefMut<T>`), `ffi::CStr`, `marker::PhantomData`,
+/// `option::Option`, `pin::Pin`, `result::Result`.
 ///
 /// and with the `more_impls` feature:
-/// `cmp::Ordering`, `convert::Infallible`, `mem::{ManuallyDrop, MaybeUninit}`, `num::NonZero*`,
-/// `ptr::NonNull`, `slice::Iter`, `sync::atomic::*`.
+/// `cell::{LazyCell, OnceCell}`, `cmp::{Ordering, Reverse}`, `convert::Infallible`,
+/// `fmt::Arguments<'varying>` (as `VaryingArguments`), `iter::{Chain, Enumerate, Zip}`,
+/// `iter::Flatten<I>` (as `FlattenFamily<I, Inner>`),
+/// `iter::Peekable<I>` (as `PeekableFamily<I, Item>`), `mem::{ManuallyDrop, MaybeUninit}`,
+/// `num::{NonZero*, Saturating, Wrapping}`,
+/// `ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive}`,
+/// `ptr::NonNull`,
+/// `slice::Iter<'varying, T>` (as `VaryingSliceIter<T>`),
+/// `slice::IterMut<'varying, T>` (as `VaryingSliceIterMut<T>`), `sync::atomic::*`.
 mod core_impls;
 
 /// Implementations for:
-/// `boxed::Box`, `borrow::Cow`, `rc::Rc`, `string::String`, `sync::Arc`, `vec::Vec`,
+/// `boxed::Box`, `borrow::Cow`, `Cow<'varying, T>` (as `VaryingCow<T>`), `ffi::CString`,
+/// `rc::Rc`, `string::String`, `sync::Arc`, `vec::Vec`.
 ///
 /// and with the `more_impls` feature:
-/// `collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque}`, `rc::Weak`, `sync::Weak`.
+/// `collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque}`,
+/// `btree_map::Entry<'varying, K, V>` (as `VaryingBTreeMapEntry<K, V>`), `rc::Weak`, `sync::Weak`.
 #[cfg(feature = "alloc")]
 mod alloc_impls;
 
 /// Implementations for:
-/// `path::{Path, PathBuf}`, `sync::{Mutex, MutexGuard}`,
+/// `path::{Path, PathBuf}`, `sync::{Mutex, MutexGuard, RwLock{Read, Write}Guard}`,
+/// `MutexGuard<'varying, T>` (as `VaryingMutexGuard<T>`), and
+/// `RwLock{Read, Write}Guard<'varying, T>` (as `VaryingRwLock{Read, Write}Guard<T>`).
 ///
 /// and with the `more_impls` feature:
-/// `cell::{OnceCell, LazyCell}`, `collections::{HashMap, HashSet}`, `io::Cursor`,
-/// `sync::{Condvar, OnceLock, RwLock, RwLock{Read, Write}Guard, LazyLock}`.
+/// `collections::{HashMap, HashSet}`,
+/// `hash_map::Entry<'varying, K, V>` (as `VaryingHashMapEntry<K, V>`), `io::Cursor`,
+/// `io::IoSlice<'varying>` (as `VaryingIoSlice`),
+/// `io::IoSliceMut<'varying>` (as `VaryingIoSliceMut`),
+/// `sync::{Condvar, OnceLock, RwLock, LazyLock}`.
 #[cfg(feature = "std")]
 mod std_impls;
 
@@ -79,19 +120,108 @@ pub use self::traits::{
     ContravariantFamily, CovariantFamily, ImplyBound, LendFamily, LifetimeFamily,
     UnvaryingFamily, Varying, WithLifetime,
 };
+pub use self::unvarying::Unvarying;
 pub use self::main_const_impls::VaryingRef;
 pub use self::main_mut_impls::VaryingRefMut;
 
 /// Module for the `Cow<'varying, T>` family, called `VaryingCow<T>`.
-pub mod borrow {}
+pub mod borrow {
+    #[cfg(feature = "alloc")]
+    pub use crate::alloc_impls::VaryingCow;
+}
 /// Module for the `cell::Ref<'varying, T>` and `cell::RefMut<'varying, T>` families,
 /// called `VaryingCellRef<T>` and `VaryingCellRefMut<T>`.
 ///
 /// The word `Cell` is added to avoid a conflict with the names of the `&'varying T` and
 /// `&'varying mut T` families.
-pub mod cell {}
-/// Module for the `slice::Iter<'varying, T>` family, called `VaryingSliceIter<T>`.
-pub mod slice {}
+pub mod cell {
+    pub use crate::core_impls::{VaryingCellRef, VaryingCellRefMut};
+}
+/// Module for the `btree_map::Entry<'varying, K, V>` and `hash_map::Entry<'varying, K, V>`
+/// families, called `VaryingBTreeMapEntry<K, V>` and `VaryingHashMapEntry<K, V>`.
+pub mod collections {
+    #[cfg(all(feature = "alloc", feature = "more_impls"))]
+    pub use crate::alloc_impls::VaryingBTreeMapEntry;
+    #[cfg(all(feature = "std", feature = "more_impls"))]
+    pub use crate::std_impls::VaryingHashMapEntry;
+}
+/// Module for the `fmt::Arguments<'varying>` family, called `VaryingArguments`.
+pub mod fmt {
+    #[cfg(feature = "more_impls")]
+    pub use crate::core_impls::VaryingArguments;
+}
+/// Module for the `io::IoSlice<'varying>` and `io::IoSliceMut<'varying>` families, called
+/// `VaryingIoSlice` and `VaryingIoSliceMut`.
+pub mod io {
+    #[cfg(all(feature = "std", feature = "more_impls"))]
+    pub use crate::std_impls::{VaryingIoSlice, VaryingIoSliceMut};
+}
+/// Module for the `iter::Peekable<I>` and `iter::Flatten<I>` families, called
+/// `PeekableFamily<I, Item>` and `FlattenFamily<I, Inner>`.
+///
+/// The `Item` family of the iterator is needed to soundly cast a peeked item, and the `Inner`
+/// family is needed to soundly cast partially-consumed inner iterators.
+///
+/// The other supported iterator adapters, `iter::{Chain, Enumerate, Zip}`, are their own
+/// families. For example, a `Zip` of two slice iterators can be shortened:
+/// ```
+/// # #[cfg(feature = "more_impls")] {
+/// use core::{iter::Zip, slice::Iter};
+/// use variance_family::CovariantFamily;
+/// use variance_family::slice::VaryingSliceIter;
+///
+/// type Bytes = VaryingSliceIter<u8>;
+///
+/// fn shorten<'a: 'b, 'b>(
+///     long: Zip<Iter<'a, u8>, Iter<'a, u8>>,
+/// ) -> Zip<Iter<'b, u8>, Iter<'b, u8>> {
+///     <Zip<Bytes, Bytes> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// let (left, right) = ([1, 2], [3, 4]);
+/// let pairs: Vec<_> = shorten(left.iter().zip(&right)).collect();
+/// assert_eq!(pairs, [(&1, &3), (&2, &4)]);
+/// # }
+/// ```
+pub mod iter {
+    #[cfg(feature = "more_impls")]
+    pub use crate::core_impls::{FlattenFamily, PeekableFamily};
+}
+/// Module for the `slice::Iter<'varying, T>` and `slice::IterMut<'varying, T>` families, called
+/// `VaryingSliceIter<T>` and `VaryingSliceIterMut<T>`.
+pub mod slice {
+    #[cfg(feature = "more_impls")]
+    pub use crate::core_impls::{VaryingSliceIter, VaryingSliceIterMut};
+}
 /// Module for the `MutexGuard<'varying, T>`, `RwLockReadGuard<'varying, T>`, and
 /// `RwLockWriteGuard<'varying, T>` families, called `Varying*Guard<T>`.
-pub mod sync {}
+pub mod sync {
+    #[cfg(feature = "std")]
+    pub use crate::std_impls::{
+        VaryingMutexGuard, VaryingRwLockReadGuard, VaryingRwLockWriteGuard,
+    };
+}
+
+/// The most commonly used traits, families, and macros of this crate.
+///
+/// The prelude is intended for application code; library code should generally prefer explicit
+/// imports. The `recursive_*` macros are not included, as they require `unsafe` to use.
+///
+/// ```
+/// use variance_family::prelude::*;
+///
+/// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), VaryingRef<str>>) -> &'b str {
+///     <VaryingRef<str> as CovariantFamily<'b, ()>>::shorten(long)
+/// }
+///
+/// assert_eq!(shorten("hello"), "hello");
+/// ```
+pub mod prelude {
+    pub use crate::{
+        ContravariantFamily, CovariantFamily, LendFamily, LifetimeFamily, UnvaryingFamily,
+        Unvarying, Varying, VaryingRef, VaryingRefMut, WithLifetime,
+    };
+    pub use crate::{bivariant, contravariant, covariant, invariant_zst, unvarying};
+    #[cfg(feature = "alloc")]
+    pub use crate::borrow::VaryingCow;
+}
@@ -1,12 +1,13 @@
 /// Create a ZST which is invariant over one or more generic parameters.
 ///
 /// Attributes (such as doc comments) may be placed on the struct. The struct may have any
-/// visibility (e.g. `pub` or the default private visibility) or name. No bounds on the generic
-/// parameters are supported other than optional `: ?Sized` bounds. Defaults for the generic
-/// parameters are not supported.
+/// visibility (e.g. `pub` or the default private visibility) or name. At least one type parameter
+/// is required, optionally preceded by lifetime parameters. No bounds on the generic parameters
+/// are supported other than optional `: ?Sized` bounds on type parameters. Defaults for the
+/// generic parameters are not supported.
 ///
 /// The created ZST wraps [`PhantomData`](::core::marker::PhantomData) and implements a
-/// variety of traits.
+/// variety of traits, including `Default`.
 ///
 /// # Example
 /// ```
@@ -31,65 +32,971 @@
 ///     }
 /// }
 /// ```
+///
+/// Alternatively, `const fn new;` may be written after the struct to also generate a
+/// `const fn new()` constructor and an `INSTANCE` associated constant, both of which have the
+/// same visibility as the struct.
+/// ```
+/// use variance_family::invariant_zst;
+///
+/// invariant_zst!(
+///     pub(crate) struct Foo<T: ?Sized, U>;
+///     const fn new;
+/// );
+///
+/// const FOO: Foo<str, u8> = Foo::new();
+/// assert_eq!(FOO, Foo::INSTANCE);
+/// ```
+///
+/// Lifetime parameters may be given before the type parameters, such as for lifetime families
+/// with a fixed lifetime other than `'varying`. The created ZST is invariant over them as well.
+/// ```
+/// use variance_family::{invariant_zst, VaryingRef, WithLifetime};
+///
+/// invariant_zst!(
+///     /// The `(&'fixed str, T<'varying>)` lifetime family.
+///     pub struct Labeled<'fixed, T: ?Sized>;
+/// );
+///
+/// impl<'fixed, 'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper>
+/// for Labeled<'fixed, T>
+/// where
+///     Upper: ?Sized,
+///     T: ?Sized + WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+/// {
+///     type Is = (&'fixed str, T::Is);
+/// }
+///
+/// let label = String::from("label");
+/// let labeled: <Labeled<'_, VaryingRef<str>> as WithLifetime<'static, 'static, ()>>::Is
+///     = (&label, "value");
+/// assert_eq!(labeled, ("label", "value"));
+/// assert_eq!(format!("{:?}", Labeled::<'_, str>::default()), "Labeled<str>");
+/// ```
 #[macro_export]
 macro_rules! invariant_zst {
     (
         $(#[$meta:meta])*
-        $vis:vis struct $name:ident<$($T:ident $(: ?$sized:ident)?),+>;
+        $vis:vis struct $name:ident<$($lt:lifetime,)* $($T:ident $(: ?$sized:ident)?),+>;
     ) => {
         // `fn` is a keyword, so there's no need for a `::core::primitive::` prefix or similar.
+        // Each lifetime appears in both argument and return position, making it invariant.
         $(#[$meta])*
-        $vis struct $name<$($T $(: ?$sized)?),+>(
-            ::core::marker::PhantomData<fn(($(*mut $T,)+))>,
+        $vis struct $name<$($lt,)* $($T $(: ?$sized)?),+>(
+            ::core::marker::PhantomData<fn(($(&$lt (),)* $(*mut $T,)+)) -> ($(&$lt (),)*)>,
         );
 
-        impl<$($T $(: ?$sized)?),+> ::core::clone::Clone for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::clone::Clone for $name<$($lt,)* $($T),+> {
             fn clone(&self) -> Self {
                 *self
             }
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::marker::Copy for $name<$($T),+> {}
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::marker::Copy for $name<$($lt,)* $($T),+> {}
 
-        impl<$($T $(: ?$sized)?),+> ::core::fmt::Debug for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::fmt::Debug for $name<$($lt,)* $($T),+> {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                ::core::write!(
-                    f,
-                    "{}<{}>",
-                    ::core::stringify!($name),
-                    ::core::any::type_name::<T>(),
-                )
+                f.write_str(::core::stringify!($name))?;
+                f.write_str("<")?;
+                let type_names = [$(::core::any::type_name::<$T>()),+];
+                for (index, type_name) in ::core::iter::IntoIterator::into_iter(type_names)
+                    .enumerate()
+                {
+                    if index != 0 {
+                        f.write_str(", ")?;
+                    }
+                    f.write_str(type_name)?;
+                }
+                f.write_str(">")
             }
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::default::Default for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::default::Default for $name<$($lt,)* $($T),+> {
             fn default() -> Self {
                 Self(::core::marker::PhantomData)
             }
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::cmp::Eq for $name<$($T),+> {}
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::cmp::Eq for $name<$($lt,)* $($T),+> {}
 
-        impl<$($T $(: ?$sized)?),+> ::core::hash::Hash for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::hash::Hash for $name<$($lt,)* $($T),+> {
             fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::cmp::Ord for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::cmp::Ord for $name<$($lt,)* $($T),+> {
             fn cmp(&self, _other: &Self) -> ::core::cmp::Ordering {
                 ::core::cmp::Ordering::Equal
             }
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::cmp::PartialEq for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::cmp::PartialEq for $name<$($lt,)* $($T),+> {
             fn eq(&self, _other: &Self) -> ::core::primitive::bool {
                 true
             }
         }
 
-        impl<$($T $(: ?$sized)?),+> ::core::cmp::PartialOrd for $name<$($T),+> {
+        impl<$($lt,)* $($T $(: ?$sized)?),+> ::core::cmp::PartialOrd for $name<$($lt,)* $($T),+> {
             fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
                 ::core::option::Option::Some(<Self as ::core::cmp::Ord>::cmp(self, other))
             }
         }
     };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$($lt:lifetime,)* $($T:ident $(: ?$sized:ident)?),+>;
+        const fn new;
+    ) => {
+        $crate::invariant_zst!(
+            $(#[$meta])*
+            $vis struct $name<$($lt,)* $($T $(: ?$sized)?),+>;
+        );
+
+        impl<$($lt,)* $($T $(: ?$sized)?),+> $name<$($lt,)* $($T),+> {
+            /// The sole value of this ZST.
+            $vis const INSTANCE: Self = Self::new();
+
+            /// Create the sole value of this ZST.
+            #[inline]
+            #[must_use]
+            $vis const fn new() -> Self {
+                Self(::core::marker::PhantomData)
+            }
+        }
+    };
+}
+
+/// Unsafely implement [`CovariantFamily`] for a lifetime family whose covariance follows from
+/// the covariance of other lifetime families.
+///
+/// The invocation looks like an `unsafe impl` block with the generic parameters of the impl
+/// placed in square brackets (and, optionally, bounds placed in a `where [..]` clause).
+/// The first two generic parameters must be the `'lower` lifetime and `Upper` type parameter
+/// of [`CovariantFamily`] (which may be given any names); an `Upper: ?Sized` bound is added
+/// automatically. The body of the invocation becomes the body of
+/// [`CovariantFamily::covariant_assertions`], and should call the `covariant_assertions` (or
+/// `contravariant_assertions`, as appropriate) of each lifetime family used by the type.
+///
+/// [`CovariantFamily::shorten`] and [`CovariantFamily::shorten_ref`] are implemented by calling
+/// `covariant_assertions` and then transmuting the `'varying` lifetime.
+///
+/// If the body starts with `type Is<'v> = Type<'v>;`, then a [`WithLifetime`] implementation
+/// with that `Is` type is generated as well, with the same generic parameters and bounds. The
+/// lifetime may be given any name not bound by a `for<..>` in the `where` clause (such as `'v`
+/// when the bounds use `for<'varying>`). Otherwise, the [`WithLifetime`] implementation must be
+/// written separately; that is needed when it should have fewer bounds than the
+/// [`CovariantFamily`] implementation, such as when the family is also given to
+/// [`recursive_contravariant`].
+///
+/// The generic parameters must not include the lifetimes `'__long`, `'__short`, `'__ref`, or
+/// `'__varying`, which are used internally.
+///
+/// # Safety
+/// If the given assertions do not panic, then `'varying` must be sound to cast covariantly in
+/// `Varying<'varying, 'lower, Upper, Self>`. See the implementation safety requirements of
+/// [`CovariantFamily`].
+///
+/// This macro cannot check that the type is actually covariant; if the compiler can prove the
+/// covariance itself, prefer a safe implementation using `{ long }` method bodies.
+///
+/// # Example
+/// ```
+/// use variance_family::{invariant_zst, recursive_covariant, CovariantFamily, Varying, VaryingRef};
+///
+/// pub struct Labeled<T> {
+///     pub label: &'static str,
+///     pub value: T,
+/// }
+///
+/// invariant_zst!(
+///     /// The `Labeled<T<'varying>>` lifetime family.
+///     pub struct LabeledFamily<T>;
+/// );
+///
+/// recursive_covariant! {
+///     // SAFETY: `Labeled<T<'varying>>` is covariant over `'varying`
+///     // if `T<'varying>` is covariant over `'varying`.
+///     unsafe impl['lower, Upper, T] for LabeledFamily<T>
+///     where [
+///         T: CovariantFamily<'lower, Upper>,
+///         for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+///     ]
+///     {
+///         type Is<'v> = Labeled<Varying<'v, 'lower, Upper, T>>;
+///
+///         T::covariant_assertions();
+///     }
+/// }
+///
+/// fn shorten<'a: 'b, 'b>(long: Labeled<&'a str>) -> Labeled<&'b str> {
+///     <LabeledFamily<VaryingRef<str>> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// let value = String::from("value");
+/// let labeled = shorten(Labeled { label: "label", value: &value });
+/// assert_eq!(labeled.value, "value");
+/// ```
+///
+/// [`CovariantFamily`]: crate::CovariantFamily
+/// [`CovariantFamily::covariant_assertions`]: crate::CovariantFamily::covariant_assertions
+/// [`CovariantFamily::shorten`]: crate::CovariantFamily::shorten
+/// [`CovariantFamily::shorten_ref`]: crate::CovariantFamily::shorten_ref
+/// [`WithLifetime`]: crate::WithLifetime
+#[macro_export]
+macro_rules! recursive_covariant {
+    (
+        $(#[$meta:meta])*
+        unsafe impl[$lower:lifetime, $Upper:ident $(, $($generics:tt)+)?] for $family:ty
+        $(where [$($bounds:tt)+])?
+        {
+            type Is<$varying:lifetime> = $Is:ty;
+            $($assertions:tt)*
+        }
+    ) => {
+        impl<$varying, $lower, $Upper: ?::core::marker::Sized $(, $($generics)+)?>
+        $crate::WithLifetime<$varying, $lower, $Upper> for $family
+        $(where $($bounds)+)?
+        {
+            type Is = $Is;
+        }
+
+        $crate::recursive_covariant! {
+            $(#[$meta])*
+            unsafe impl[$lower, $Upper $(, $($generics)+)?] for $family
+            $(where [$($bounds)+])?
+            {
+                $($assertions)*
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        unsafe impl[$lower:lifetime, $Upper:ident $(, $($generics:tt)+)?] for $family:ty
+        $(where [$($bounds:tt)+])?
+        {
+            $($assertions:tt)*
+        }
+    ) => {
+        $(#[$meta])*
+        unsafe impl<$lower, $Upper: ?::core::marker::Sized $(, $($generics)+)?>
+        $crate::CovariantFamily<$lower, $Upper> for $family
+        $(where $($bounds)+)?
+        {
+            #[inline]
+            fn covariant_assertions() {
+                $($assertions)*
+            }
+
+            #[inline]
+            fn shorten<'__long, '__short>(
+                long: $crate::Varying<'__long, $lower, $Upper, Self>,
+            ) -> $crate::Varying<'__short, $lower, $Upper, Self>
+            where
+                $Upper: '__long,
+                '__long: '__short,
+                '__short: $lower,
+                for<'__varying> $crate::Varying<'__varying, $lower, $Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                <Self as $crate::CovariantFamily<$lower, $Upper>>::covariant_assertions();
+
+                let src = ::core::mem::ManuallyDrop::new(long);
+                // SAFETY: we are shortening the `'__long` lifetime to `'__short`, which is at least
+                // as long as `$lower`. We called `Self::covariant_assertions()`, so the caller of
+                // this macro asserts that covariantly casting `Self<'varying>` is sound.
+                // Changing lifetimes does not change the layout of a type, so reading the
+                // `Self<'__short>` out of the `ManuallyDrop` is sound, and the source value is
+                // never dropped.
+                unsafe { ::core::mem::transmute_copy(&src) }
+            }
+
+            #[inline]
+            fn shorten_ref<'__long, '__short, '__ref>(
+                long: &'__ref $crate::Varying<'__long, $lower, $Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__short, $lower, $Upper, Self>
+            where
+                $Upper: '__long,
+                '__long: '__short,
+                '__short: $lower,
+                $crate::Varying<'__long, $lower, $Upper, Self>: '__ref,
+                $crate::Varying<'__short, $lower, $Upper, Self>: '__ref,
+            {
+                <Self as $crate::CovariantFamily<$lower, $Upper>>::covariant_assertions();
+
+                // SAFETY: we are shortening the `'__long` lifetime to `'__short`, which is at least
+                // as long as `$lower`. We called `Self::covariant_assertions()`, so the caller of
+                // this macro asserts that covariantly casting `Self<'varying>` is sound.
+                unsafe { ::core::mem::transmute(long) }
+            }
+        }
+    };
+}
+
+/// Unsafely implement [`ContravariantFamily`] for a lifetime family whose contravariance follows
+/// from the variance of other lifetime families.
+///
+/// This is the same as [`recursive_covariant`], except that the body of the invocation becomes
+/// the body of [`ContravariantFamily::contravariant_assertions`], and
+/// [`ContravariantFamily::lengthen`] and [`ContravariantFamily::lengthen_ref`] are implemented.
+///
+/// As with [`recursive_covariant`], the [`WithLifetime`] implementation is generated if the body
+/// starts with `type Is<'v> = Type<'v>;`, and must otherwise be written separately (as in the
+/// example below).
+///
+/// The generic parameters must not include the lifetimes `'__long`, `'__short`, `'__ref`, or
+/// `'__varying`, which are used internally.
+///
+/// # Safety
+/// If the given assertions do not panic, then `'varying` must be sound to cast contravariantly in
+/// `Varying<'varying, 'lower, Upper, Self>`. See the implementation safety requirements of
+/// [`ContravariantFamily`].
+///
+/// This macro cannot check that the type is actually contravariant; if the compiler can prove the
+/// contravariance itself, prefer a safe implementation using `{ short }` method bodies.
+///
+/// # Example
+/// ```
+/// use variance_family::{
+///     invariant_zst, recursive_contravariant, ContravariantFamily, Varying, VaryingRef,
+///     WithLifetime,
+/// };
+///
+/// pub struct Callback<T> {
+///     pub name: &'static str,
+///     pub callback: T,
+/// }
+///
+/// invariant_zst!(
+///     /// The `Callback<T<'varying>>` lifetime family.
+///     pub struct CallbackFamily<T>;
+/// );
+///
+/// impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for CallbackFamily<T>
+/// where
+///     Upper: ?Sized,
+///     T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+/// {
+///     type Is = Callback<T::Is>;
+/// }
+///
+/// recursive_contravariant! {
+///     // SAFETY: `Callback<T<'varying>>` is contravariant over `'varying`
+///     // if `T<'varying>` is contravariant over `'varying`.
+///     unsafe impl['lower, Upper, T] for CallbackFamily<T>
+///     where [
+///         T: ContravariantFamily<'lower, Upper>,
+///         for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+///     ]
+///     {
+///         T::contravariant_assertions();
+///     }
+/// }
+///
+/// fn lengthen<'a: 'b, 'b>(short: Callback<fn(&'b str)>) -> Callback<fn(&'a str)> {
+///     <CallbackFamily<fn(VaryingRef<str>)> as ContravariantFamily<'b, &'a ()>>::lengthen(short)
+/// }
+///
+/// fn print(s: &str) {
+///     println!("{s}");
+/// }
+///
+/// let callback = lengthen(Callback { name: "print", callback: print });
+/// (callback.callback)("hello");
+/// ```
+///
+/// [`ContravariantFamily`]: crate::ContravariantFamily
+/// [`ContravariantFamily::contravariant_assertions`]: crate::ContravariantFamily::contravariant_assertions
+/// [`ContravariantFamily::lengthen`]: crate::ContravariantFamily::lengthen
+/// [`ContravariantFamily::lengthen_ref`]: crate::ContravariantFamily::lengthen_ref
+/// [`WithLifetime`]: crate::WithLifetime
+#[macro_export]
+macro_rules! recursive_contravariant {
+    (
+        $(#[$meta:meta])*
+        unsafe impl[$lower:lifetime, $Upper:ident $(, $($generics:tt)+)?] for $family:ty
+        $(where [$($bounds:tt)+])?
+        {
+            type Is<$varying:lifetime> = $Is:ty;
+            $($assertions:tt)*
+        }
+    ) => {
+        impl<$varying, $lower, $Upper: ?::core::marker::Sized $(, $($generics)+)?>
+        $crate::WithLifetime<$varying, $lower, $Upper> for $family
+        $(where $($bounds)+)?
+        {
+            type Is = $Is;
+        }
+
+        $crate::recursive_contravariant! {
+            $(#[$meta])*
+            unsafe impl[$lower, $Upper $(, $($generics)+)?] for $family
+            $(where [$($bounds)+])?
+            {
+                $($assertions)*
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        unsafe impl[$lower:lifetime, $Upper:ident $(, $($generics:tt)+)?] for $family:ty
+        $(where [$($bounds:tt)+])?
+        {
+            $($assertions:tt)*
+        }
+    ) => {
+        $(#[$meta])*
+        unsafe impl<$lower, $Upper: ?::core::marker::Sized $(, $($generics)+)?>
+        $crate::ContravariantFamily<$lower, $Upper> for $family
+        $(where $($bounds)+)?
+        {
+            #[inline]
+            fn contravariant_assertions() {
+                $($assertions)*
+            }
+
+            #[inline]
+            fn lengthen<'__short, '__long>(
+                short: $crate::Varying<'__short, $lower, $Upper, Self>,
+            ) -> $crate::Varying<'__long, $lower, $Upper, Self>
+            where
+                $Upper: '__long,
+                '__long: '__short,
+                '__short: $lower,
+                for<'__varying> $crate::Varying<'__varying, $lower, $Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                <Self as $crate::ContravariantFamily<$lower, $Upper>>::contravariant_assertions();
+
+                let src = ::core::mem::ManuallyDrop::new(short);
+                // SAFETY: we are lengthening the `'__short` lifetime to `'__long`, which is at most
+                // as long as any lifetime in `$Upper`. We called
+                // `Self::contravariant_assertions()`, so the caller of this macro asserts that
+                // contravariantly casting `Self<'varying>` is sound. Changing lifetimes does not
+                // change the layout of a type, so reading the `Self<'__long>` out of the
+                // `ManuallyDrop` is sound, and the source value is never dropped.
+                unsafe { ::core::mem::transmute_copy(&src) }
+            }
+
+            #[inline]
+            fn lengthen_ref<'__short, '__long, '__ref>(
+                short: &'__ref $crate::Varying<'__short, $lower, $Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__long, $lower, $Upper, Self>
+            where
+                $Upper: '__long,
+                '__long: '__short,
+                '__short: $lower,
+                $crate::Varying<'__long, $lower, $Upper, Self>: '__ref,
+                $crate::Varying<'__short, $lower, $Upper, Self>: '__ref,
+            {
+                <Self as $crate::ContravariantFamily<$lower, $Upper>>::contravariant_assertions();
+
+                // SAFETY: we are lengthening the `'__short` lifetime to `'__long`, which is at most
+                // as long as any lifetime in `$Upper`. We called
+                // `Self::contravariant_assertions()`, so the caller of this macro asserts that
+                // contravariantly casting `Self<'varying>` is sound.
+                unsafe { ::core::mem::transmute(short) }
+            }
+        }
+    };
+}
+
+/// Create a lifetime family which wraps another covariant lifetime family, and is itself
+/// covariant.
+///
+/// The invocation is the same as for [`invariant_zst`], except that exactly one generic parameter
+/// (the wrapped lifetime family) must be given. The created ZST `Family<T>` implements
+/// [`WithLifetime`] with `Is = T::Is`, and implements [`CovariantFamily`] whenever `T` does, by
+/// delegating to `T`'s implementation.
+///
+/// This is useful for giving a lifetime family a local name, such as to implement a local trait
+/// for a lifetime family from another crate.
+///
+/// # Example
+/// ```
+/// use variance_family::{covariant, CovariantFamily, VaryingRef};
+///
+/// covariant!(
+///     /// A local name for the `&'varying str` lifetime family.
+///     pub struct StrRef<T: ?Sized>;
+/// );
+///
+/// fn shorten<'a: 'b, 'b>(long: &'a str) -> &'b str {
+///     <StrRef<VaryingRef<str>> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// assert_eq!(shorten("value"), "value");
+/// ```
+///
+/// The wrapper is only covariant if the wrapped family is covariant:
+/// ```compile_fail
+/// use variance_family::{covariant, CovariantFamily, VaryingRef};
+///
+/// covariant!(pub struct Callback<T: ?Sized>;);
+///
+/// fn shorten<'a: 'b, 'b>(long: fn(&'a str)) -> fn(&'b str) {
+///     // `fn(&'varying str)` is contravariant, not covariant, over `'varying`.
+///     <Callback<fn(VaryingRef<str>)> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+/// ```
+///
+/// # Newtype families
+/// Alternatively, a fieldless struct can be given along with the type that the family produces,
+/// written as `type Is<'varying> = Type<'varying>;` (with any name for the lifetime). The
+/// created struct implements [`WithLifetime`] with that `Is` type, and implements
+/// [`CovariantFamily`] with trivial `{ long }` method bodies, which only compile if the compiler
+/// itself can prove that the type is covariant over the lifetime. This is useful for local types
+/// with a lifetime parameter, such as newtype wrappers around references.
+///
+/// ```
+/// use variance_family::{covariant, CovariantFamily, Varying};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// pub struct Wrapper<'a>(pub &'a str);
+///
+/// covariant!(
+///     /// The `Wrapper<'varying>` lifetime family.
+///     pub struct WrapperFamily;
+///     type Is<'varying> = Wrapper<'varying>;
+/// );
+///
+/// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), WrapperFamily>) -> Wrapper<'b> {
+///     <WrapperFamily as CovariantFamily<'b, ()>>::shorten(long)
+/// }
+///
+/// let value = String::from("value");
+/// assert_eq!(shorten(Wrapper(&value)), Wrapper("value"));
+/// ```
+///
+/// A type which is not covariant over the lifetime is rejected:
+/// ```compile_fail
+/// use variance_family::covariant;
+///
+/// pub struct Callback<'a>(pub fn(&'a str));
+///
+/// covariant!(
+///     pub struct CallbackFamily;
+///     type Is<'varying> = Callback<'varying>;
+/// );
+/// ```
+///
+/// [`CovariantFamily`]: crate::CovariantFamily
+/// [`WithLifetime`]: crate::WithLifetime
+#[macro_export]
+macro_rules! covariant {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$T:ident $(: ?$sized:ident)?> $(;)?
+    ) => {
+        $crate::invariant_zst!(
+            $(#[$meta])*
+            $vis struct $name<$T $(: ?$sized)?>;
+        );
+
+        $crate::covariant!(@with_lifetime $name<$T $(: ?$sized)?>);
+        $crate::covariant!(@covariant $name<$T $(: ?$sized)?>);
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident;
+        type Is<$varying:lifetime> = $Is:ty $(;)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name;
+
+        impl<$varying, __Upper> $crate::WithLifetime<$varying, '_, __Upper> for $name
+        where
+            __Upper: ?::core::marker::Sized,
+        {
+            type Is = $Is;
+        }
+
+        // SAFETY: the compiler checks that the trivial method bodies are sound, which requires
+        // that `$Is` is covariant over `$varying`. No assertions are included.
+        unsafe impl<'__lower, __Upper> $crate::CovariantFamily<'__lower, __Upper> for $name
+        where
+            __Upper: ?::core::marker::Sized,
+        {
+            #[inline]
+            fn covariant_assertions() {}
+
+            #[inline]
+            fn shorten<'__long, '__short>(
+                long: $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                for<'__varying> $crate::Varying<'__varying, '__lower, __Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                long
+            }
+
+            #[inline]
+            fn shorten_ref<'__long, '__short, '__ref>(
+                long: &'__ref $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                $crate::Varying<'__long, '__lower, __Upper, Self>: '__ref,
+                $crate::Varying<'__short, '__lower, __Upper, Self>: '__ref,
+            {
+                long
+            }
+        }
+    };
+    (@with_lifetime $name:ident<$T:ident $(: ?$sized:ident)?>) => {
+        impl<'__varying, '__lower, __Upper, $T> $crate::WithLifetime<'__varying, '__lower, __Upper>
+        for $name<$T>
+        where
+            __Upper: ?::core::marker::Sized,
+            $T: $(?$sized +)? $crate::WithLifetime<'__varying, '__lower, __Upper>,
+        {
+            type Is = $T::Is;
+        }
+    };
+    (@covariant $name:ident<$T:ident $(: ?$sized:ident)?>) => {
+        // SAFETY: `$name<$T>` has the same `WithLifetime` types as `$T`, and this implementation
+        // delegates to the implementation of `$T`.
+        unsafe impl<'__lower, __Upper, $T> $crate::CovariantFamily<'__lower, __Upper> for $name<$T>
+        where
+            __Upper: ?::core::marker::Sized,
+            $T: $(?$sized +)? $crate::CovariantFamily<'__lower, __Upper>,
+        {
+            #[inline]
+            fn covariant_assertions() {
+                <$T as $crate::CovariantFamily<'__lower, __Upper>>::covariant_assertions();
+            }
+
+            #[inline]
+            fn shorten<'__long, '__short>(
+                long: $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                for<'__varying> $crate::Varying<'__varying, '__lower, __Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                <$T as $crate::CovariantFamily<'__lower, __Upper>>::shorten(long)
+            }
+
+            #[inline]
+            fn shorten_ref<'__long, '__short, '__ref>(
+                long: &'__ref $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                $crate::Varying<'__long, '__lower, __Upper, Self>: '__ref,
+                $crate::Varying<'__short, '__lower, __Upper, Self>: '__ref,
+            {
+                <$T as $crate::CovariantFamily<'__lower, __Upper>>::shorten_ref(long)
+            }
+        }
+    };
+}
+
+/// Create a lifetime family which wraps another contravariant lifetime family, and is itself
+/// contravariant.
+///
+/// This is the same as [`covariant`], except that the created ZST `Family<T>` implements
+/// [`ContravariantFamily`] whenever `T` does, by delegating to `T`'s implementation.
+///
+/// # Example
+/// ```
+/// use variance_family::{contravariant, ContravariantFamily, VaryingRef};
+///
+/// contravariant!(
+///     /// A local name for the `fn(&'varying str)` lifetime family.
+///     pub struct StrCallback<T: ?Sized>;
+/// );
+///
+/// fn lengthen<'a: 'b, 'b>(short: fn(&'b str)) -> fn(&'a str) {
+///     <StrCallback<fn(VaryingRef<str>)> as ContravariantFamily<'b, &'a ()>>::lengthen(short)
+/// }
+///
+/// fn print(s: &str) {
+///     println!("{s}");
+/// }
+///
+/// lengthen(print)("hello");
+/// ```
+///
+/// The wrapper is only contravariant if the wrapped family is contravariant:
+/// ```compile_fail
+/// use variance_family::{contravariant, ContravariantFamily, VaryingRef};
+///
+/// contravariant!(pub struct StrRef<T: ?Sized>;);
+///
+/// fn lengthen<'a: 'b, 'b>(short: &'b str) -> &'a str {
+///     // `&'varying str` is covariant, not contravariant, over `'varying`.
+///     <StrRef<VaryingRef<str>> as ContravariantFamily<'b, &'a ()>>::lengthen(short)
+/// }
+/// ```
+///
+/// [`ContravariantFamily`]: crate::ContravariantFamily
+#[macro_export]
+macro_rules! contravariant {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$T:ident $(: ?$sized:ident)?> $(;)?
+    ) => {
+        $crate::invariant_zst!(
+            $(#[$meta])*
+            $vis struct $name<$T $(: ?$sized)?>;
+        );
+
+        $crate::covariant!(@with_lifetime $name<$T $(: ?$sized)?>);
+        $crate::contravariant!(@contravariant $name<$T $(: ?$sized)?>);
+    };
+    (@contravariant $name:ident<$T:ident $(: ?$sized:ident)?>) => {
+        // SAFETY: `$name<$T>` has the same `WithLifetime` types as `$T`, and this implementation
+        // delegates to the implementation of `$T`.
+        unsafe impl<'__lower, __Upper, $T> $crate::ContravariantFamily<'__lower, __Upper>
+        for $name<$T>
+        where
+            __Upper: ?::core::marker::Sized,
+            $T: $(?$sized +)? $crate::ContravariantFamily<'__lower, __Upper>,
+        {
+            #[inline]
+            fn contravariant_assertions() {
+                <$T as $crate::ContravariantFamily<'__lower, __Upper>>::contravariant_assertions();
+            }
+
+            #[inline]
+            fn lengthen<'__short, '__long>(
+                short: $crate::Varying<'__short, '__lower, __Upper, Self>,
+            ) -> $crate::Varying<'__long, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                for<'__varying> $crate::Varying<'__varying, '__lower, __Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                <$T as $crate::ContravariantFamily<'__lower, __Upper>>::lengthen(short)
+            }
+
+            #[inline]
+            fn lengthen_ref<'__short, '__long, '__ref>(
+                short: &'__ref $crate::Varying<'__short, '__lower, __Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__long, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                $crate::Varying<'__short, '__lower, __Upper, Self>: '__ref,
+                $crate::Varying<'__long, '__lower, __Upper, Self>: '__ref,
+            {
+                <$T as $crate::ContravariantFamily<'__lower, __Upper>>::lengthen_ref(short)
+            }
+        }
+    };
+}
+
+/// Create a lifetime family which wraps another lifetime family, and is covariant and/or
+/// contravariant whenever the wrapped lifetime family is.
+///
+/// This combines [`covariant`] and [`contravariant`]: the created ZST `Family<T>` implements
+/// [`CovariantFamily`] whenever `T` does and [`ContravariantFamily`] whenever `T` does.
+///
+/// # Example
+/// ```
+/// use variance_family::{bivariant, ContravariantFamily, CovariantFamily, VaryingRef};
+///
+/// bivariant!(
+///     /// A local name for any lifetime family.
+///     pub struct Local<T: ?Sized>;
+/// );
+///
+/// fn shorten<'a: 'b, 'b>(long: &'a str) -> &'b str {
+///     <Local<VaryingRef<str>> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// fn lengthen<'a: 'b, 'b>(short: fn(&'b str)) -> fn(&'a str) {
+///     <Local<fn(VaryingRef<str>)> as ContravariantFamily<'b, &'a ()>>::lengthen(short)
+/// }
+///
+/// fn print(s: &str) {
+///     println!("{s}");
+/// }
+///
+/// lengthen(print)(shorten("hello"));
+/// ```
+///
+/// Neither variance is available if the wrapped family is invariant:
+/// ```compile_fail
+/// use variance_family::{bivariant, CovariantFamily, VaryingRef};
+///
+/// bivariant!(pub struct Local<T: ?Sized>;);
+///
+/// fn shorten<'a: 'b, 'b>(long: *mut &'a str) -> *mut &'b str {
+///     // `*mut &'varying str` is invariant over `'varying`.
+///     <Local<*mut VaryingRef<str>> as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+/// ```
+///
+/// [`CovariantFamily`]: crate::CovariantFamily
+/// [`ContravariantFamily`]: crate::ContravariantFamily
+#[macro_export]
+macro_rules! bivariant {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident<$T:ident $(: ?$sized:ident)?> $(;)?
+    ) => {
+        $crate::invariant_zst!(
+            $(#[$meta])*
+            $vis struct $name<$T $(: ?$sized)?>;
+        );
+
+        $crate::covariant!(@with_lifetime $name<$T $(: ?$sized)?>);
+        $crate::covariant!(@covariant $name<$T $(: ?$sized)?>);
+        $crate::contravariant!(@contravariant $name<$T $(: ?$sized)?>);
+    };
+}
+
+/// Define a type which does not use `'varying` at all, and make it a lifetime family of itself.
+///
+/// The invocation is a struct definition (a unit, tuple, or braced struct, with any attributes
+/// and visibility); generic parameters are not supported. The struct implements [`WithLifetime`]
+/// with `Is = Self`, and implements [`CovariantFamily`] and [`ContravariantFamily`]
+/// unconditionally.
+///
+/// This is safe, as the struct cannot name `'varying` (which is not in scope), so its layout and
+/// validity are identical across all lifetimes; the compiler checks the casts, which are
+/// implemented with trivial `{ long }` and `{ short }` bodies.
+///
+/// For types defined elsewhere, or generic types, use [`Unvarying<T>`] instead.
+///
+/// # Example
+/// ```
+/// use variance_family::{unvarying, ContravariantFamily, CovariantFamily, VaryingRef};
+///
+/// unvarying!(
+///     /// Metadata stored alongside a self-reference.
+///     #[derive(Debug, PartialEq)]
+///     pub struct Metadata {
+///         pub line: usize,
+///         pub name: &'static str,
+///     }
+/// );
+///
+/// type Entry = (VaryingRef<str>, Metadata);
+///
+/// fn shorten<'a: 'b, 'b>(long: (&'a str, Metadata)) -> (&'b str, Metadata) {
+///     <Entry as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// fn main() {
+///     let metadata = Metadata { line: 1, name: "main" };
+///     assert_eq!(shorten(("fn main()", metadata)).1.line, 1);
+/// }
+/// ```
+///
+/// The struct cannot use `'varying`:
+/// ```compile_fail
+/// use variance_family::unvarying;
+///
+/// unvarying!(pub struct Borrowed(&'varying str););
+/// ```
+///
+/// [`CovariantFamily`]: crate::CovariantFamily
+/// [`ContravariantFamily`]: crate::ContravariantFamily
+/// [`Unvarying<T>`]: crate::Unvarying
+/// [`WithLifetime`]: crate::WithLifetime
+#[macro_export]
+macro_rules! unvarying {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident $($body:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis struct $name $($body)*
+
+        impl<__Upper: ?::core::marker::Sized> $crate::WithLifetime<'_, '_, __Upper> for $name {
+            type Is = Self;
+        }
+
+        // SAFETY: `$name` does not use `'varying` at all, so covariant casts are sound and the
+        // compiler checks the trivial method bodies. No assertions are included.
+        unsafe impl<'__lower, __Upper> $crate::CovariantFamily<'__lower, __Upper> for $name
+        where
+            __Upper: ?::core::marker::Sized,
+        {
+            #[inline]
+            fn covariant_assertions() {}
+
+            #[inline]
+            fn shorten<'__long, '__short>(
+                long: $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                for<'__varying> $crate::Varying<'__varying, '__lower, __Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                long
+            }
+
+            #[inline]
+            fn shorten_ref<'__long, '__short, '__ref>(
+                long: &'__ref $crate::Varying<'__long, '__lower, __Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__short, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                $crate::Varying<'__long, '__lower, __Upper, Self>: '__ref,
+                $crate::Varying<'__short, '__lower, __Upper, Self>: '__ref,
+            {
+                long
+            }
+        }
+
+        // SAFETY: `$name` does not use `'varying` at all, so contravariant casts are sound and
+        // the compiler checks the trivial method bodies. No assertions are included.
+        unsafe impl<'__lower, __Upper> $crate::ContravariantFamily<'__lower, __Upper> for $name
+        where
+            __Upper: ?::core::marker::Sized,
+        {
+            #[inline]
+            fn contravariant_assertions() {}
+
+            #[inline]
+            fn lengthen<'__short, '__long>(
+                short: $crate::Varying<'__short, '__lower, __Upper, Self>,
+            ) -> $crate::Varying<'__long, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                for<'__varying> $crate::Varying<'__varying, '__lower, __Upper, Self>:
+                    ::core::marker::Sized,
+            {
+                short
+            }
+
+            #[inline]
+            fn lengthen_ref<'__short, '__long, '__ref>(
+                short: &'__ref $crate::Varying<'__short, '__lower, __Upper, Self>,
+            ) -> &'__ref $crate::Varying<'__long, '__lower, __Upper, Self>
+            where
+                __Upper: '__long,
+                '__long: '__short,
+                '__short: '__lower,
+                $crate::Varying<'__long, '__lower, __Upper, Self>: '__ref,
+                $crate::Varying<'__short, '__lower, __Upper, Self>: '__ref,
+            {
+                short
+            }
+        }
+    };
 }
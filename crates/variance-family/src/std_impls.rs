@@ -0,0 +1,2000 @@
+use core::mem::{ManuallyDrop, transmute, transmute_copy};
+#[cfg(feature = "more_impls")]
+use std::collections::{HashMap, HashSet, hash_map::Entry as HashMapEntry};
+#[cfg(feature = "more_impls")]
+use std::io::{Cursor, IoSlice, IoSliceMut};
+#[cfg(feature = "more_impls")]
+use std::sync::{LazyLock, OnceLock};
+use std::path::{Path, PathBuf};
+use std::sync::{MutexGuard, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::invariant_zst;
+use crate::traits::{ContravariantFamily, CovariantFamily, UnvaryingFamily, Varying, WithLifetime};
+
+
+// Note: in below safety comments, "is covariant over" or "is contravariant over" means, more
+// precisely, "is sound to covariantly (or contravariantly) cast with respect to". That is,
+// manually-proven variance (and manually-proven soundness of casts) is the relevant concern,
+// not compiler-assigned variance (and compiler-proven soundness of casts).
+
+// ================================================================
+//  Path
+// ================================================================
+
+// Safety summary:
+// - `Path` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for Path {
+    type Is = Self;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for Path {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for Path {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  PathBuf
+// ================================================================
+
+// Safety summary:
+// - `PathBuf` is bivariant over `'varying` (as it's entirely unused).
+
+impl<Upper: ?Sized> WithLifetime<'_, '_, Upper> for PathBuf {
+    type Is = Self;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for PathBuf {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> ContravariantFamily<'lower, Upper> for PathBuf {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  MutexGuard<'a, T>
+// ================================================================
+
+// Safety summary:
+// - `MutexGuard<'a, U>` is bivariant over `'varying` (as it's entirely unused). Below,
+//   `T<'varying>` families are used which implement `UnvaryingFamily`, making them equivalent to
+//   `MutexGuard<'a, U>` for some type `U`. Unsafe transmutes aren't even needed.
+// - Like `&'a mut T<'varying>`, the guard provides mutable access to `T<'varying>`, so
+//   `MutexGuard<'a, T<'varying>>` is not covariant or contravariant over `'varying` in general.
+
+impl<'a, 'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for MutexGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'a,
+{
+    type Is = MutexGuard<'a, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> CovariantFamily<'lower, Upper> for MutexGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> ContravariantFamily<'lower, Upper> for MutexGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  MutexGuard<'varying, T>    (VaryingMutexGuard<T>)
+// ================================================================
+
+// Safety summary:
+// - `MutexGuard<'varying, U>` is covariant over `'varying`. Below, `T<'varying>` families are used
+//   which implement `UnvaryingFamily`, making them equivalent to `MutexGuard<'varying, U>` for
+//   some type `U`. Unsafe transmutes aren't even needed.
+// - `MutexGuard<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+invariant_zst!(
+    /// The `MutexGuard<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` does not actually use `'varying` at all (making it some fixed type `U`
+    /// regardless of `'varying`), then `MutexGuard<'varying, T<'varying>>` is covariant over
+    /// `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingMutexGuard<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for VaryingMutexGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'varying,
+{
+    type Is = MutexGuard<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `T<'varying>` does not
+//   actually use `'varying` at all, so `MutexGuard<'varying, T<'varying>>` can be treated as
+//   `MutexGuard<'varying, U>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingMutexGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `MutexGuard<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  RwLockReadGuard<'a, T>
+// ================================================================
+
+// Safety summary:
+// - `RwLockReadGuard<'a, T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant
+//   over `'varying`.
+// - `RwLockReadGuard<'a, T<'varying>>` is contravariant over `'varying` if `T<'varying>` is
+//   contravariant over it.
+//
+// Like `&'a T<'varying>`, the guard only provides shared access to the `T<'varying>` value, and
+// releasing the lock does not involve `T<'varying>` in any way.
+
+impl<'a, 'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper>
+for RwLockReadGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'a,
+{
+    type Is = RwLockReadGuard<'a, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic,
+//   in which case `T<'varying>` is covariant over `'varying`,
+//   implying that `RwLockReadGuard<'a, T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> CovariantFamily<'lower, Upper> for RwLockReadGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + CovariantFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<RwLockReadGuard<'a, Varying<'l, 'lower, Upper, T>>>
+            = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `RwLockReadGuard<'a, T<'s>>` out of the `ManuallyDrop` is sound, and the source
+        // value is never dropped.
+        let dst: RwLockReadGuard<'a, Varying<'s, 'lower, Upper, T>> = unsafe {
+            transmute_copy(&src)
+        };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RwLockReadGuard<'a, Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RwLockReadGuard<'a, Varying<'s, 'lower, Upper, T>> = unsafe {
+            transmute(src)
+        };
+        dst
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic,
+//   in which case `T<'varying>` is contravariant over `'varying`,
+//   implying that `RwLockReadGuard<'a, T<'varying>>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> ContravariantFamily<'lower, Upper> for RwLockReadGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + ContravariantFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::contravariant_assertions()`
+        // call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<RwLockReadGuard<'a, Varying<'s, 'lower, Upper, T>>>
+            = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `T::contravariant_assertions()`
+        // (within `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>`
+        // is sound. Changing lifetimes does not change the layout of a type, so reading the
+        // `RwLockReadGuard<'a, T<'l>>` out of the `ManuallyDrop` is sound, and the source
+        // value is never dropped.
+        let dst: RwLockReadGuard<'a, Varying<'l, 'lower, Upper, T>> = unsafe {
+            transmute_copy(&src)
+        };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::contravariant_assertions()`
+        // call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r RwLockReadGuard<'a, Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is
+        // at most as long as any lifetime in `Upper`. We called `T::contravariant_assertions()`
+        // (within `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>`
+        // is sound.
+        let dst: &'r RwLockReadGuard<'a, Varying<'l, 'lower, Upper, T>> = unsafe {
+            transmute(src)
+        };
+        dst
+    }
+}
+
+
+// ================================================================
+//  RwLockReadGuard<'varying, T>    (VaryingRwLockReadGuard<T>)
+// ================================================================
+
+// Safety summary:
+// - `RwLockReadGuard<'varying, T<'varying>>` is covariant over `'varying` if `T<'varying>` is
+//   covariant over it.
+// - `RwLockReadGuard<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+invariant_zst!(
+    /// The `RwLockReadGuard<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` is covariant over `'varying`, then `RwLockReadGuard<'varying, T<'varying>>`
+    /// is covariant over `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingRwLockReadGuard<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper>
+for VaryingRwLockReadGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'varying,
+{
+    type Is = RwLockReadGuard<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic,
+//   in which case `T<'varying>` is covariant over `'varying`,
+//   implying that `RwLockReadGuard<'varying, T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingRwLockReadGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + CovariantFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: RwLockReadGuard<'s, Varying<'l, 'lower, Upper, T>> = long;
+        let src = ManuallyDrop::new(src);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the
+        // `RwLockReadGuard<'s, T<'s>>` out of the `ManuallyDrop` is sound, and the source
+        // value is never dropped.
+        let dst: RwLockReadGuard<'s, Varying<'s, 'lower, Upper, T>> = unsafe {
+            transmute_copy(&src)
+        };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions.
+        // There are no possible sources of panics other than the `Self::covariant_assertions()`
+        // call.
+
+        Self::covariant_assertions();
+
+        let src: &'r RwLockReadGuard<'l, Varying<'l, 'lower, Upper, T>> = long;
+        let src: &'r RwLockReadGuard<'s, Varying<'l, 'lower, Upper, T>> = src;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is
+        // at least as long as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r RwLockReadGuard<'s, Varying<'s, 'lower, Upper, T>> = unsafe {
+            transmute(src)
+        };
+        dst
+    }
+}
+
+// `RwLockReadGuard<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
+
+
+// ================================================================
+//  RwLockWriteGuard<'a, T>
+// ================================================================
+
+// Safety summary:
+// - `RwLockWriteGuard<'a, U>` is bivariant over `'varying` (as it's entirely unused). Below,
+//   `T<'varying>` families are used which implement `UnvaryingFamily`, making them equivalent to
+//   `RwLockWriteGuard<'a, U>` for some type `U`. Unsafe transmutes aren't even needed.
+// - Like `&'a mut T<'varying>`, the guard provides mutable access to `T<'varying>`, so
+//   `RwLockWriteGuard<'a, T<'varying>>` is not covariant or contravariant over `'varying` in
+//   general.
+
+impl<'a, 'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper>
+for RwLockWriteGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'a,
+{
+    type Is = RwLockWriteGuard<'a, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> CovariantFamily<'lower, Upper> for RwLockWriteGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'a, 'lower, Upper, T> ContravariantFamily<'lower, Upper> for RwLockWriteGuard<'a, T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'a,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  RwLockWriteGuard<'varying, T>    (VaryingRwLockWriteGuard<T>)
+// ================================================================
+
+// Safety summary:
+// - `RwLockWriteGuard<'varying, U>` is covariant over `'varying`. Below, `T<'varying>` families
+//   are used which implement `UnvaryingFamily`, making them equivalent to
+//   `RwLockWriteGuard<'varying, U>` for some type `U`. Unsafe transmutes aren't even needed.
+// - `RwLockWriteGuard<'varying, T<'varying>>` is never contravariant over `'varying`.
+
+invariant_zst!(
+    /// The `RwLockWriteGuard<'varying, T<'varying>>` lifetime family.
+    ///
+    /// If `T<'varying>` does not actually use `'varying` at all (making it some fixed type `U`
+    /// regardless of `'varying`), then `RwLockWriteGuard<'varying, T<'varying>>` is covariant over
+    /// `'varying`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingRwLockWriteGuard<T: ?Sized>;
+);
+
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper>
+for VaryingRwLockWriteGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + WithLifetime<'varying, 'lower, Upper>,
+    T::Is: 'varying,
+{
+    type Is = RwLockWriteGuard<'varying, T::Is>;
+}
+
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `T<'varying>` does not
+//   actually use `'varying` at all, so `RwLockWriteGuard<'varying, T<'varying>>` can be treated
+//   as `RwLockWriteGuard<'varying, U>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for VaryingRwLockWriteGuard<T>
+where
+    Upper: ?Sized,
+    T: ?Sized + UnvaryingFamily<'lower, Upper>,
+    for<'varying> <T as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `RwLockWriteGuard<'varying, T<'varying>>` is never contravariant over `'varying`. It's always at
+// best covariant, never bivariant.
+
+
+// ================================================================
+//  HashMap<K, V, S>
+// ================================================================
+
+// Safety summary:
+// - `HashMap<K<'varying>, V<'varying>, S>` is covariant over `'varying` if `K<'varying>` and
+//   `V<'varying>` are covariant over `'varying`.
+// - `HashMap<K<'varying>, V<'varying>, S>` is contravariant over `'varying` if `K<'varying>` and
+//   `V<'varying>` are contravariant over `'varying`.
+//
+// Changing lifetimes cannot affect the behavior of `Hash` or `Eq` implementations, so the
+// casted map remains valid.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, K, V, S> WithLifetime<'varying, 'lower, Upper> for HashMap<K, V, S>
+where
+    Upper: ?Sized,
+    K: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+    V: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = HashMap<K::Is, V::Is, S>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `K::covariant_assertions()` and `V::covariant_assertions()` do not
+//   panic, in which case `K<'varying>` and `V<'varying>` are covariant over `'varying`, implying
+//   that `HashMap<K<'varying>, V<'varying>, S>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, K, V, S> CovariantFamily<'lower, Upper> for HashMap<K, V, S>
+where
+    Upper: ?Sized,
+    K: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, K>: Sized,
+    V: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, V>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        K::covariant_assertions();
+        V::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `K<'l>` and `V<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `K::covariant_assertions()` and
+        // `V::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `K<'varying>` and `V<'varying>` is sound. Changing lifetimes does not change the
+        // layout of a type, so reading the `HashMap<K<'s>, V<'s>, S>` out of the `ManuallyDrop` is
+        // sound, and the source value is never dropped.
+        let dst: HashMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+            S,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r HashMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+            S,
+        > = long;
+        // SAFETY: we are shortening the `'l` lifetime of `K<'l>` and `V<'l>` to `'s`, which is at
+        // least as long as `'lower`. We called `K::covariant_assertions()` and
+        // `V::covariant_assertions()` (within `Self::covariant_assertions()`), so covariantly
+        // casting `K<'varying>` and `V<'varying>` is sound.
+        let dst: &'r HashMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+            S,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `K::contravariant_assertions()` and `V::contravariant_assertions()` do
+//   not panic, in which case `K<'varying>` and `V<'varying>` are contravariant over `'varying`,
+//   implying that `HashMap<K<'varying>, V<'varying>, S>` is contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, K, V, S> ContravariantFamily<'lower, Upper> for HashMap<K, V, S>
+where
+    Upper: ?Sized,
+    K: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, K>: Sized,
+    V: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, V>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        K::contravariant_assertions();
+        V::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `K<'s>` and `V<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `K::contravariant_assertions()` and
+        // `V::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `K<'varying>` and `V<'varying>` is sound. Changing lifetimes does
+        // not change the layout of a type, so reading the `HashMap<K<'l>, V<'l>, S>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: HashMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+            S,
+        > = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r HashMap<
+            Varying<'s, 'lower, Upper, K>,
+            Varying<'s, 'lower, Upper, V>,
+            S,
+        > = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `K<'s>` and `V<'s>` to `'l`, which is at
+        // most as long as any lifetime in `Upper`. We called `K::contravariant_assertions()` and
+        // `V::contravariant_assertions()` (within `Self::contravariant_assertions()`), so
+        // contravariantly casting `K<'varying>` and `V<'varying>` is sound.
+        let dst: &'r HashMap<
+            Varying<'l, 'lower, Upper, K>,
+            Varying<'l, 'lower, Upper, V>,
+            S,
+        > = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  HashSet<T, S>
+// ================================================================
+
+// Safety summary:
+// - `HashSet<T<'varying>, S>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `HashSet<T<'varying>, S>` is contravariant over `'varying` if `T<'varying>` is contravariant
+//   over `'varying`.
+//
+// Changing lifetimes cannot affect the behavior of `Hash` or `Eq` implementations, so the
+// casted set remains valid.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T, S> WithLifetime<'varying, 'lower, Upper> for HashSet<T, S>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = HashSet<T::Is, S>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `HashSet<T<'varying>, S>` is covariant over
+//   `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T, S> CovariantFamily<'lower, Upper> for HashSet<T, S>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<HashSet<Varying<'l, 'lower, Upper, T>, S>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `HashSet<T<'s>, S>` out of
+        // the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: HashSet<Varying<'s, 'lower, Upper, T>, S> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r HashSet<Varying<'l, 'lower, Upper, T>, S> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r HashSet<Varying<'s, 'lower, Upper, T>, S> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `HashSet<T<'varying>, S>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T, S> ContravariantFamily<'lower, Upper> for HashSet<T, S>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<HashSet<Varying<'s, 'lower, Upper, T>, S>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `HashSet<T<'l>,
+        // S>` out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: HashSet<Varying<'l, 'lower, Upper, T>, S> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r HashSet<Varying<'s, 'lower, Upper, T>, S> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r HashSet<Varying<'l, 'lower, Upper, T>, S> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  hash_map::Entry<'varying, K, V>    (VaryingHashMapEntry<K, V>, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `hash_map::Entry<'varying, K2, V2>` is covariant over `'varying`, like `&'varying mut T`.
+//   Below, `K<'varying>` and `V<'varying>` families are used which implement `UnvaryingFamily`,
+//   making the family equivalent to `hash_map::Entry<'varying, K2, V2>` for some types `K2` and
+//   `V2`. Unsafe transmutes aren't even needed.
+// - `hash_map::Entry<'varying, K<'varying>, V<'varying>>` is never contravariant over `'varying`.
+
+#[cfg(feature = "more_impls")]
+invariant_zst!(
+    /// The `hash_map::Entry<'varying, K<'varying>, V<'varying>>` lifetime family.
+    ///
+    /// If `K<'varying>` and `V<'varying>` do not actually use `'varying` at all (making them some
+    /// fixed types regardless of `'varying`), then this family is covariant over `'varying`.
+    /// Since an entry mutably borrows its map, the keys and values of the map cannot vary with
+    /// `'varying`, even for vacant entries. The fixed key and value types must also outlive every
+    /// `'varying` lifetime, so they are effectively required to be `'static`.
+    ///
+    /// This lifetime family is never contravariant over `'varying`.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    pub struct VaryingHashMapEntry<K, V>;
+);
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, K, V> WithLifetime<'varying, 'lower, Upper>
+for VaryingHashMapEntry<K, V>
+where
+    Upper: ?Sized,
+    K: WithLifetime<'varying, 'lower, Upper, Is: Sized + 'varying>,
+    V: WithLifetime<'varying, 'lower, Upper, Is: Sized + 'varying>,
+{
+    type Is = HashMapEntry<'varying, K::Is, V::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `K<'varying>` and
+//   `V<'varying>` do not actually use `'varying` at all, so
+//   `hash_map::Entry<'varying, K<'varying>, V<'varying>>` can be treated as
+//   `hash_map::Entry<'varying, K2, V2>`, which is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, K, V> CovariantFamily<'lower, Upper> for VaryingHashMapEntry<K, V>
+where
+    Upper: ?Sized,
+    K: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+    for<'varying> <K as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+    V: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+    for<'varying> <V as WithLifetime<'varying, 'lower, Upper>>::Is: 'varying,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `hash_map::Entry<'varying, K<'varying>, V<'varying>>` is never contravariant over `'varying`.
+// It's always at best covariant, never bivariant.
+
+
+// ================================================================
+//  Cursor<T>
+// ================================================================
+
+// Safety summary:
+// - `Cursor<T<'varying>>` is covariant over `'varying` if `T<'varying>` is covariant over
+//   `'varying`.
+// - `Cursor<T<'varying>>` is contravariant over `'varying` if `T<'varying>` is contravariant over
+//   `'varying`.
+//
+// The cursor position is a plain `u64` which does not involve `T<'varying>` in any way.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for Cursor<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = Cursor<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   The former implies that `T::covariant_assertions()` does not panic, in which case `T<'varying>`
+//   is covariant over `'varying`, implying that `Cursor<T<'varying>>` is covariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::covariant_assertions()`.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for Cursor<T>
+where
+    Upper: ?Sized,
+    T: CovariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn covariant_assertions() {
+        T::covariant_assertions();
+    }
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: ManuallyDrop<Cursor<Varying<'l, 'lower, Upper, T>>> = ManuallyDrop::new(long);
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound. Changing
+        // lifetimes does not change the layout of a type, so reading the `Cursor<T<'s>>` out of the
+        // `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Cursor<Varying<'s, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a covariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::covariant_assertions()` call.
+
+        Self::covariant_assertions();
+
+        let src: &'r Cursor<Varying<'l, 'lower, Upper, T>> = long;
+        // SAFETY: we are shortening the `'l` lifetime of `T<'l>` to `'s`, which is at least as long
+        // as `'lower`. We called `T::covariant_assertions()` (within
+        // `Self::covariant_assertions()`), so covariantly casting `T<'varying>` is sound.
+        let dst: &'r Cursor<Varying<'s, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   The former implies that `T::contravariant_assertions()` does not panic, in which case
+//   `T<'varying>` is contravariant over `'varying`, implying that `Cursor<T<'varying>>` is
+//   contravariant over `'varying`.
+//
+// - No assertions are included other than those in `Self::contravariant_assertions()`.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for Cursor<T>
+where
+    Upper: ?Sized,
+    T: ContravariantFamily<'lower, Upper>,
+    for<'varying> Varying<'varying, 'lower, Upper, T>: Sized,
+{
+    #[inline]
+    fn contravariant_assertions() {
+        T::contravariant_assertions();
+    }
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: ManuallyDrop<Cursor<Varying<'s, 'lower, Upper, T>>> = ManuallyDrop::new(short);
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        // Changing lifetimes does not change the layout of a type, so reading the `Cursor<T<'l>>`
+        // out of the `ManuallyDrop` is sound, and the source value is never dropped.
+        let dst: Cursor<Varying<'l, 'lower, Upper, T>> = unsafe { transmute_copy(&src) };
+        dst
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: this is a contravariant cast with some assertions. There are no
+        // possible sources of panics other than the `Self::contravariant_assertions()` call.
+
+        Self::contravariant_assertions();
+
+        let src: &'r Cursor<Varying<'s, 'lower, Upper, T>> = short;
+        // SAFETY: we are lengthening the `'s` lifetime of `T<'s>` to `'l`, which is at most as long
+        // as any lifetime in `Upper`. We called `T::contravariant_assertions()` (within
+        // `Self::contravariant_assertions()`), so contravariantly casting `T<'varying>` is sound.
+        let dst: &'r Cursor<Varying<'l, 'lower, Upper, T>> = unsafe { transmute(src) };
+        dst
+    }
+}
+
+
+// ================================================================
+//  io::IoSlice<'varying>    (VaryingIoSlice, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `io::IoSlice<'varying>` is covariant over `'varying`, and the compiler knows it.
+//   Unsafe transmutes aren't even needed.
+// - `io::IoSlice<'varying>` is never contravariant over `'varying`.
+
+/// The `io::IoSlice<'varying>` lifetime family.
+///
+/// This lifetime family is covariant over `'varying`, and is never contravariant over
+/// `'varying`.
+///
+/// Note that this type itself is just a marker ZST for the family.
+#[cfg(feature = "more_impls")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VaryingIoSlice;
+
+#[cfg(feature = "more_impls")]
+impl<'varying, Upper: ?Sized> WithLifetime<'varying, '_, Upper> for VaryingIoSlice {
+    type Is = IoSlice<'varying>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `io::IoSlice<'varying>`
+//   is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for VaryingIoSlice {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `io::IoSlice<'varying>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  io::IoSliceMut<'varying>    (VaryingIoSliceMut, with the `more_impls` feature)
+// ================================================================
+
+// Safety summary:
+// - `io::IoSliceMut<'varying>` is covariant over `'varying` (like `&'varying mut [u8]`, since
+//   the bytes themselves do not depend on `'varying`), and the compiler knows it. Unsafe
+//   transmutes aren't even needed.
+// - `io::IoSliceMut<'varying>` is never contravariant over `'varying`.
+
+/// The `io::IoSliceMut<'varying>` lifetime family.
+///
+/// This lifetime family is covariant over `'varying`, and is never contravariant over
+/// `'varying`.
+///
+/// Note that this type itself is just a marker ZST for the family.
+#[cfg(feature = "more_impls")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VaryingIoSliceMut;
+
+#[cfg(feature = "more_impls")]
+impl<'varying, Upper: ?Sized> WithLifetime<'varying, '_, Upper> for VaryingIoSliceMut {
+    type Is = IoSliceMut<'varying>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `io::IoSliceMut<'varying>`
+//   is covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized> CovariantFamily<'lower, Upper> for VaryingIoSliceMut {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// `io::IoSliceMut<'varying>` is never contravariant over `'varying`. It's always at best
+// covariant, never bivariant.
+
+
+// ================================================================
+//  OnceLock<T>
+// ================================================================
+
+// Safety summary:
+// - `OnceLock<U>` is bivariant over `'varying` (as it's entirely unused). Below, `T<'varying>`
+//   families are used which implement `UnvaryingFamily`, making them equivalent to `OnceLock<U>`
+//   for some type `U`. Unsafe transmutes aren't even needed.
+// - Like `OnceCell<T<'varying>>`, a shared `&OnceLock<T<'varying>>` can be used to set the value,
+//   so `OnceLock<T<'varying>>` is not covariant or contravariant over `'varying` in general.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T> WithLifetime<'varying, 'lower, Upper> for OnceLock<T>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = OnceLock<T::Is>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T> CovariantFamily<'lower, Upper> for OnceLock<T>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T> ContravariantFamily<'lower, Upper> for OnceLock<T>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
+
+
+// ================================================================
+//  LazyLock<T, F>
+// ================================================================
+
+// Safety summary:
+// - `LazyLock<U, F>` is bivariant over `'varying` (as it's entirely unused). Below, `T<'varying>`
+//   families are used which implement `UnvaryingFamily`, making them equivalent to
+//   `LazyLock<U, F>` for some type `U`. Unsafe transmutes aren't even needed.
+// - Like `LazyCell<T<'varying>, F>`, the value is initialized through a shared reference, and `F`
+//   is a fixed type which must produce a `T<'varying>`, so `LazyLock<T<'varying>, F>` is not
+//   covariant or contravariant over `'varying` in general.
+//
+// Unlike `LazyCell`, a `LazyLock` may be initialized on any thread that can access it, but that
+// makes no difference to its variance.
+
+#[cfg(feature = "more_impls")]
+impl<'varying, 'lower, Upper, T, F> WithLifetime<'varying, 'lower, Upper> for LazyLock<T, F>
+where
+    Upper: ?Sized,
+    T: WithLifetime<'varying, 'lower, Upper, Is: Sized>,
+{
+    type Is = LazyLock<T::Is, F>;
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::covariant_assertions()` does not panic,
+//   then `Self<'varying>` is covariant over `'varying`.
+//
+//   `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper, T, F> CovariantFamily<'lower, Upper> for LazyLock<T, F>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+#[cfg(feature = "more_impls")]
+// SAFETY:
+// - If `Self::contravariant_assertions()` does not panic,
+//   then `Self<'varying>` is contravariant over `'varying`.
+//
+//   `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+//
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper, T, F> ContravariantFamily<'lower, Upper> for LazyLock<T, F>
+where
+    Upper: ?Sized,
+    T: UnvaryingFamily<'lower, Upper, WithAnyLifetime: Sized>,
+{
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}
@@ -239,6 +239,26 @@ where
 /// a single type, this trait can be soundly implemented. Examples include `u8`, `[u8]`, and
 /// `&'a [u8]`.
 ///
+/// A tuple family is covariant if each of its elements is covariant:
+/// ```
+/// # use variance_family::{CovariantFamily, Unvarying, VaryingRef};
+/// type Pair = (VaryingRef<str>, VaryingRef<Unvarying<[u8]>>);
+///
+/// fn shorten<'a: 'b, 'b>(long: (&'a str, &'a [u8])) -> (&'b str, &'b [u8]) {
+///     <Pair as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+/// ```
+///
+/// so a single contravariant element prevents the whole tuple from being covariant:
+/// ```compile_fail
+/// # use variance_family::{CovariantFamily, VaryingRef};
+/// type Pair = (VaryingRef<str>, fn(VaryingRef<str>));
+///
+/// fn shorten<'a: 'b, 'b>(long: (&'a str, fn(&'a str))) -> (&'b str, fn(&'b str)) {
+///     <Pair as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+/// ```
+///
 /// Additionally, the family might have some non-covariant variance over `'varying` assigned by the
 /// compiler, but it may still be sound to implement this trait. A type might, for instance, gate
 /// any parts of its interface that would normally rely on contravariance or invariance behind
@@ -313,7 +333,19 @@ pub unsafe trait CovariantFamily<'lower, Upper: ?Sized>: LifetimeFamily<'lower,
     /// Perform compile-time assertions, which may cause post-monomorphization errors.
     ///
     /// (The function could, hypothetically, also include runtime assertions.)
-    // TODO: `const` block example
+    ///
+    /// Compile-time assertions can be written with `const` blocks, which are evaluated when the
+    /// function is monomorphized. Only conditions on types and constants can be checked this way;
+    /// lifetimes are erased before monomorphization, so nothing about `'varying` can be asserted.
+    /// ```
+    /// # trait Flag { const COVARIANT: bool; }
+    /// # struct Foo<F>(F);
+    /// # impl<F: Flag> Foo<F> {
+    /// fn covariant_assertions() {
+    ///     const { assert!(F::COVARIANT, "`F` does not permit covariant casts") };
+    /// }
+    /// # }
+    /// ```
     #[inline]
     fn covariant_assertions() {}
 
@@ -471,6 +503,17 @@ pub unsafe trait CovariantFamily<'lower, Upper: ?Sized>: LifetimeFamily<'lower,
 /// a single type, this trait can be soundly implemented. Examples include `u8`, `[u8]`, and
 /// `&'a [u8]`.
 ///
+/// A tuple family is contravariant only if each of its elements is contravariant, so a single
+/// covariant element prevents the whole tuple from being contravariant:
+/// ```compile_fail
+/// # use variance_family::{ContravariantFamily, VaryingRef};
+/// type Pair = (VaryingRef<str>, fn(VaryingRef<str>));
+///
+/// fn lengthen<'a: 'b, 'b>(short: (&'b str, fn(&'b str))) -> (&'a str, fn(&'a str)) {
+///     <Pair as ContravariantFamily<'b, &'a ()>>::lengthen(short)
+/// }
+/// ```
+///
 /// Additionally, the family might have some non-contravariant variance over `'varying` assigned by
 /// the compiler, but it may still be sound to implement this trait. A type might, for instance,
 /// gate any parts of its interface that would normally rely on covariance or invariance behind
@@ -482,7 +525,19 @@ pub unsafe trait ContravariantFamily<'lower, Upper: ?Sized>: LifetimeFamily<'low
     /// Perform compile-time assertions, which may cause post-monomorphization errors.
     ///
     /// (The function could, hypothetically, also include runtime assertions.)
-    // TODO: `const` block example
+    ///
+    /// Compile-time assertions can be written with `const` blocks, which are evaluated when the
+    /// function is monomorphized. Only conditions on types and constants can be checked this way;
+    /// lifetimes are erased before monomorphization, so nothing about `'varying` can be asserted.
+    /// ```
+    /// # trait Flag { const CONTRAVARIANT: bool; }
+    /// # struct Foo<F>(F);
+    /// # impl<F: Flag> Foo<F> {
+    /// fn contravariant_assertions() {
+    ///     const { assert!(F::CONTRAVARIANT, "`F` does not permit contravariant casts") };
+    /// }
+    /// # }
+    /// ```
     #[inline]
     fn contravariant_assertions() {}
 
@@ -568,6 +623,24 @@ pub unsafe trait ContravariantFamily<'lower, Upper: ?Sized>: LifetimeFamily<'low
 ///
 /// If `Upper` has no lifetimes, the upper bound on `'varying` is `'static`. If `Upper` does
 /// contain lifetimes, the upper bound is the shortest lifetime in `Upper`.
+///
+/// # Example
+/// Views of `aliasable-view` are lend families whose `Upper` bound is the source of the view.
+/// More generally, a `LendFamily` bound suffices to shorten the `'varying` lifetime of any
+/// `Sized` family:
+/// ```
+/// use variance_family::{CovariantFamily, LendFamily, Varying, VaryingRef};
+///
+/// fn shorten<'a: 'b, 'b, L: LendFamily<&'a ()>>(
+///     long: Varying<'a, 'b, &'a (), L>,
+/// ) -> Varying<'b, 'b, &'a (), L> {
+///     <L as CovariantFamily<'b, &'a ()>>::shorten(long)
+/// }
+///
+/// let text = String::from("hello");
+/// let shortened: &str = shorten::<VaryingRef<str>>(&text);
+/// assert_eq!(shortened, "hello");
+/// ```
 pub trait LendFamily<Upper>
 where
     Upper: ?Sized,
@@ -0,0 +1,129 @@
+use crate::invariant_zst;
+use crate::traits::{ContravariantFamily, CovariantFamily, Varying, WithLifetime};
+
+
+// ================================================================
+//  Unvarying<T>
+// ================================================================
+
+// Safety summary:
+// - `Unvarying<T>` is bivariant over `'varying` (as it's entirely unused).
+
+invariant_zst!(
+    /// The trivial lifetime family that is always `T`, regardless of `'varying`.
+    ///
+    /// This lifetime family is both covariant and contravariant over `'varying`, and implements
+    /// [`UnvaryingFamily`] with `WithAnyLifetime = T`.
+    ///
+    /// This is the canonical way to opt a specific type out of `'varying` within a composite
+    /// family, or to use a type which does not implement [`WithLifetime`] itself.
+    ///
+    /// Note that this type itself is just a marker ZST for the family.
+    ///
+    /// # Example
+    /// ```
+    /// use variance_family::{CovariantFamily, Unvarying, Varying, VaryingRef};
+    ///
+    /// type Entry = (VaryingRef<str>, Unvarying<Vec<u8>>);
+    ///
+    /// fn shorten<'a: 'b, 'b>(long: Varying<'a, 'b, (), Entry>) -> (&'b str, Vec<u8>) {
+    ///     <Entry as CovariantFamily<'b, ()>>::shorten(long)
+    /// }
+    ///
+    /// assert_eq!(shorten(("key", vec![1, 2])), ("key", vec![1, 2]));
+    /// ```
+    ///
+    /// [`UnvaryingFamily`]: crate::UnvaryingFamily
+    /// [`WithLifetime`]: crate::WithLifetime
+    pub struct Unvarying<T: ?Sized>;
+);
+
+impl<T: ?Sized, Upper: ?Sized> WithLifetime<'_, '_, Upper> for Unvarying<T> {
+    type Is = T;
+}
+
+// SAFETY:
+// - `Self::covariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it covariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `shorten` and `shorten_ref` are met.
+unsafe impl<'lower, Upper: ?Sized, T: ?Sized> CovariantFamily<'lower, Upper> for Unvarying<T> {
+    #[inline]
+    fn covariant_assertions() {}
+
+    #[inline]
+    fn shorten<'l, 's>(
+        long: Varying<'l, 'lower, Upper, Self>,
+    ) -> Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+
+    #[inline]
+    fn shorten_ref<'l, 's, 'r>(
+        long: &'r Varying<'l, 'lower, Upper, Self>,
+    ) -> &'r Varying<'s, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ long }` is always safe.
+
+        long
+    }
+}
+
+// SAFETY:
+// - `Self::contravariant_assertions()` is trivial and never panics, and `Self<'varying>` does not
+//   actually use `'varying` at all, making it contravariant over `'varying`.
+// - No assertions are included.
+// - The implementation safety requirements of `lengthen` and `lengthen_ref` are met.
+unsafe impl<'lower, Upper: ?Sized, T: ?Sized> ContravariantFamily<'lower, Upper> for Unvarying<T> {
+    #[inline]
+    fn contravariant_assertions() {}
+
+    #[inline]
+    fn lengthen<'s, 'l>(
+        short: Varying<'s, 'lower, Upper, Self>,
+    ) -> Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        for<'varying> Varying<'varying, 'lower, Upper, Self>: Sized,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+
+    #[inline]
+    fn lengthen_ref<'s, 'l, 'r>(
+        short: &'r Varying<'s, 'lower, Upper, Self>,
+    ) -> &'r Varying<'l, 'lower, Upper, Self>
+    where
+        Upper: 'l,
+        'l: 's,
+        's: 'lower,
+        Varying<'l, 'lower, Upper, Self>: 'r,
+        Varying<'s, 'lower, Upper, Self>: 'r,
+    {
+        #![expect(clippy::unnecessary_safety_comment, reason = "implementation safety of method")]
+        // Implementation safety: implementing this with `{ short }` is always safe.
+
+        short
+    }
+}